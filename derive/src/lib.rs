@@ -0,0 +1,228 @@
+//! Derive macro for the `str-reader` crate.
+//!
+//! The crate provides `#[derive(FromReader)]` for structs. Fields are read in
+//! declaration order using their `FromReader` implementations. The following
+//! field attributes can be used to describe the input format:
+//!
+//! * `#[reader(prefix = "...")]` - match a given literal before the field
+//! * `#[reader(suffix = "...")]` - match a given literal after the field
+//! * `#[reader(skip_whitespace)]` - skip whitespace before the literals
+//! * `#[reader(optional)]` - the field is an `Option` and it is set to `None`
+//!   if it (including its prefix and suffix) cannot be read
+//!
+//! Type parameters used in fields are required to implement `FromReader`
+//! as well. The macro is re-exported by `str-reader` if the `derive` feature
+//! is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use str_reader::{FromReader, StringReader};
+//!
+//! #[derive(FromReader)]
+//! struct Entry<'a> {
+//!     name: &'a str,
+//!     #[reader(skip_whitespace, prefix = "=")]
+//!     value: u32,
+//!     #[reader(optional, skip_whitespace, prefix = "#")]
+//!     comment: Option<&'a str>,
+//! }
+//!
+//! let mut reader = StringReader::new("foo = 12 # bar");
+//!
+//! let entry: Entry = reader.parse().unwrap();
+//!
+//! assert_eq!(entry.name, "foo");
+//! assert_eq!(entry.value, 12);
+//! assert_eq!(entry.comment, Some("bar"));
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Fields, Lifetime, LitStr,
+};
+
+/// Derive the `FromReader` trait for a struct.
+#[proc_macro_derive(FromReader, attributes(reader))]
+pub fn derive_from_reader(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Reading options of a single field.
+#[derive(Default)]
+struct FieldOptions {
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    skip_whitespace: bool,
+    optional: bool,
+}
+
+impl FieldOptions {
+    /// Parse the options from field attributes.
+    fn from_field(field: &Field) -> Result<Self, Error> {
+        let mut res = Self::default();
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("reader") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("prefix") {
+                    res.prefix = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("suffix") {
+                    res.suffix = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip_whitespace") {
+                    res.skip_whitespace = true;
+                } else if meta.path.is_ident("optional") {
+                    res.optional = true;
+                } else {
+                    return Err(meta.error("unknown reader attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(res)
+    }
+
+    /// Generate code for matching a given literal.
+    fn literal(&self, literal: Option<&LitStr>) -> TokenStream2 {
+        let literal = match literal {
+            Some(literal) => literal,
+            None => return TokenStream2::new(),
+        };
+
+        let skip_whitespace = if self.skip_whitespace {
+            quote!(reader.skip_whitespace();)
+        } else {
+            TokenStream2::new()
+        };
+
+        quote! {
+            #skip_whitespace
+            reader.match_str(#literal)?;
+        }
+    }
+
+    /// Generate an expression reading the field.
+    fn read(&self, lifetime: &Lifetime) -> TokenStream2 {
+        let prefix = self.literal(self.prefix.as_ref());
+        let suffix = self.literal(self.suffix.as_ref());
+
+        let read = quote! {
+            |reader: &mut ::str_reader::StringReader<#lifetime>| {
+                #prefix
+                let value = ::str_reader::FromReader::from_reader(reader)?;
+                #suffix
+                ::core::result::Result::<_, ::str_reader::ParseError>::Ok(value)
+            }
+        };
+
+        if self.optional {
+            quote!(reader.opt(#read))
+        } else {
+            quote!(reader.transaction(#read)?)
+        }
+    }
+}
+
+/// Expand the derive macro.
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FromReader can be derived only for structs",
+            ))
+        }
+    };
+
+    // Structs with exactly one lifetime parameter borrow from the input.
+    let mut lifetimes = input.generics.lifetimes();
+
+    let lifetime = match (lifetimes.next(), lifetimes.next()) {
+        (Some(param), None) => param.lifetime.clone(),
+        _ => Lifetime::new("'__reader", Span::call_site()),
+    };
+
+    let mut reads = Vec::new();
+
+    for field in data.fields.iter() {
+        let options = FieldOptions::from_field(field)?;
+
+        reads.push(options.read(&lifetime));
+    }
+
+    let construct = match &data.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+
+            quote!(Self { #(#names: #reads,)* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#reads,)*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let mut generics = input.generics.clone();
+
+    if lifetime.ident == "__reader" {
+        generics.params.insert(0, parse_quote!(#lifetime));
+    }
+
+    // Type parameters used in fields must be readable as well.
+    let bounded = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .filter(|&ident| {
+            data.fields
+                .iter()
+                .any(|field| mentions(field.ty.to_token_stream(), ident))
+        })
+        .collect::<Vec<_>>();
+
+    let where_clause = generics.make_where_clause();
+
+    for ident in bounded {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ident: ::str_reader::FromReader<#lifetime>));
+    }
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let name = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics ::str_reader::FromReader<#lifetime> for #name #ty_generics
+        #where_clause
+        {
+            fn from_reader(
+                reader: &mut ::str_reader::StringReader<#lifetime>,
+            ) -> ::core::result::Result<Self, ::str_reader::ParseError> {
+                reader.transaction(|reader| {
+                    ::core::result::Result::<Self, ::str_reader::ParseError>::Ok(#construct)
+                })
+            }
+        }
+    })
+}
+
+/// Check if a given token stream mentions a given identifier.
+fn mentions(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(other) => other == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -0,0 +1,172 @@
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Options for reading boolean values.
+///
+/// By default, only `true` and `false` are accepted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BoolOptions {
+    numeric: bool,
+    yes_no: bool,
+    ignore_case: bool,
+}
+
+impl BoolOptions {
+    /// Create new boolean options with the default settings.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            numeric: false,
+            yes_no: false,
+            ignore_case: false,
+        }
+    }
+
+    /// Accept `1` and `0`.
+    #[inline]
+    pub fn numeric(mut self, enable: bool) -> Self {
+        self.numeric = enable;
+        self
+    }
+
+    /// Accept `yes` and `no`.
+    #[inline]
+    pub fn yes_no(mut self, enable: bool) -> Self {
+        self.yes_no = enable;
+        self
+    }
+
+    /// Ignore ASCII case of the values (e.g. accept `True` or `NO`).
+    #[inline]
+    pub fn ignore_case(mut self, enable: bool) -> Self {
+        self.ignore_case = enable;
+        self
+    }
+
+    /// Parse a given word.
+    fn parse(&self, word: &str) -> Option<bool> {
+        let eq = |a: &str, b: &str| {
+            if self.ignore_case {
+                a.eq_ignore_ascii_case(b)
+            } else {
+                a == b
+            }
+        };
+
+        if eq(word, "true") || (self.yes_no && eq(word, "yes")) || (self.numeric && word == "1") {
+            Some(true)
+        } else if eq(word, "false")
+            || (self.yes_no && eq(word, "no"))
+            || (self.numeric && word == "0")
+        {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for BoolOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read the next word as a boolean value (`true` or `false`). The input
+    /// won't be advanced if the word is not a boolean value.
+    #[inline]
+    pub fn read_bool(&mut self) -> Result<bool, ParseError> {
+        self.read_bool_with(&BoolOptions::new())
+    }
+
+    /// Read the next word as a boolean value using given options. The input
+    /// won't be advanced if the word is not a boolean value.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - accepted boolean values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{BoolOptions, StringReader};
+    ///
+    /// let options = BoolOptions::new().yes_no(true).ignore_case(true);
+    ///
+    /// let mut reader = StringReader::new("Yes no");
+    ///
+    /// assert_eq!(reader.read_bool_with(&options), Ok(true));
+    /// assert_eq!(reader.read_bool_with(&options), Ok(false));
+    /// ```
+    pub fn read_bool_with(&mut self, options: &BoolOptions) -> Result<bool, ParseError> {
+        let (word, rest) = self.first_word();
+
+        if word.is_empty() {
+            let err = self
+                .error(ErrorKind::EmptyInput)
+                .with_expected(Expected::Description("boolean"));
+
+            return Err(err);
+        }
+
+        match options.parse(word) {
+            Some(res) => {
+                self.advance_to(rest);
+
+                Ok(res)
+            }
+            None => {
+                let input = self.input.as_str();
+
+                let start = &input[input.len() - rest.len() - word.len()..];
+
+                let err = ParseError::new(ErrorKind::InvalidValue, self.position_of(start))
+                    .with_expected(Expected::Description("boolean"))
+                    .with_found(word.chars().next());
+
+                Err(err)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoolOptions;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_bool() {
+        let mut reader = StringReader::new(" true false True 1");
+
+        assert_eq!(reader.read_bool(), Ok(true));
+        assert_eq!(reader.read_bool(), Ok(false));
+
+        let err = reader.read_bool().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 13);
+        assert_eq!(reader.as_str(), " True 1");
+
+        let options = BoolOptions::new().ignore_case(true).numeric(true);
+
+        assert_eq!(reader.read_bool_with(&options), Ok(true));
+        assert_eq!(reader.read_bool_with(&options), Ok(true));
+        assert_eq!(
+            reader.read_bool_with(&options).unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+
+        let mut reader = StringReader::new("yes no 0");
+
+        assert!(reader.read_bool().is_err());
+
+        let options = BoolOptions::new().yes_no(true);
+
+        assert_eq!(reader.read_bool_with(&options), Ok(true));
+        assert_eq!(reader.read_bool_with(&options), Ok(false));
+        assert!(reader.read_bool_with(&options).is_err());
+    }
+}
@@ -0,0 +1,421 @@
+use core::str::{self, FromStr};
+
+use crate::{
+    num, ErrorKind, Expected, NumberError, ParseError, Position, ReadableFloat, ReadableInt,
+    StringReader,
+};
+
+/// Byte slice reader.
+///
+/// The reader provides a subset of the `StringReader` API for inputs that
+/// are not guaranteed to be valid UTF-8 (e.g. network protocol messages).
+/// Parts of the input can be converted into a `StringReader` after UTF-8
+/// validation. Columns of positions reported by this reader are counted in
+/// bytes and words are delimited by ASCII whitespace.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::BytesReader;
+///
+/// let mut reader = BytesReader::new(b"LEN 5\r\nhello\xff");
+///
+/// reader.match_bytes(b"LEN ").unwrap();
+///
+/// let len = reader.read_int_inline::<usize>().unwrap();
+///
+/// reader.match_bytes(b"\r\n").unwrap();
+///
+/// let mut body = reader.read_utf8(len).unwrap();
+///
+/// assert_eq!(body.read_word(), "hello");
+/// assert_eq!(reader.as_bytes(), b"\xff");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BytesReader<'a> {
+    input: &'a [u8],
+    position: Position,
+}
+
+impl<'a> BytesReader<'a> {
+    /// Create a new reader for a given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - input bytes
+    pub fn new<T>(input: &'a T) -> Self
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        Self {
+            input: input.as_ref(),
+            position: Position::START,
+        }
+    }
+
+    /// Get the current byte (if any) without advancing the input.
+    #[inline]
+    pub fn current_byte(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Check if the remaining input starts with given bytes.
+    #[inline]
+    pub fn starts_with(&self, val: &[u8]) -> bool {
+        self.input.starts_with(val)
+    }
+
+    /// Get the current position.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Read the next byte.
+    pub fn read_byte(&mut self) -> Result<u8, ParseError> {
+        let b = self.current_byte().ok_or_else(|| {
+            self.error(ErrorKind::EmptyInput)
+                .with_expected(Expected::Description("byte"))
+        })?;
+
+        self.split_to(1);
+
+        Ok(b)
+    }
+
+    /// Match the next byte to a given one. The input won't be advanced in
+    /// case of an error.
+    pub fn match_byte(&mut self, expected: u8) -> Result<(), ParseError> {
+        self.match_bytes(&[expected])
+    }
+
+    /// Match given bytes to the input. The input won't be advanced in case of
+    /// an error. The error points at the first byte that does not match.
+    pub fn match_bytes(&mut self, val: &[u8]) -> Result<(), ParseError> {
+        let len = self
+            .input
+            .iter()
+            .zip(val)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        if len == val.len() {
+            self.split_to(len);
+
+            return Ok(());
+        }
+
+        let mut position = self.position;
+
+        position.advance_bytes(&self.input[..len]);
+
+        let kind = if len < self.input.len() {
+            ErrorKind::NoMatch
+        } else {
+            ErrorKind::EmptyInput
+        };
+
+        let err = ParseError::new(kind, position)
+            .with_expected(Expected::Byte(val[len]))
+            .with_found_byte(self.input.get(len).copied());
+
+        Err(err)
+    }
+
+    /// Skip the next byte.
+    #[inline]
+    pub fn skip_byte(&mut self) {
+        if !self.input.is_empty() {
+            self.split_to(1);
+        }
+    }
+
+    /// Skip all ASCII whitespace.
+    #[inline]
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(|b| b.is_ascii_whitespace());
+    }
+
+    /// Read until a given condition is true or until the end of the input
+    /// and return the bytes.
+    pub fn read_until<F>(&mut self, mut cnd: F) -> &'a [u8]
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let index = self
+            .input
+            .iter()
+            .position(|&b| cnd(b))
+            .unwrap_or(self.input.len());
+
+        self.split_to(index)
+    }
+
+    /// Read while a given condition is true or until the end of the input
+    /// and return the bytes.
+    #[inline]
+    pub fn read_while<F>(&mut self, mut cnd: F) -> &'a [u8]
+    where
+        F: FnMut(u8) -> bool,
+    {
+        self.read_until(|b| !cnd(b))
+    }
+
+    /// Skip while a given condition is true or until the end of the input.
+    /// The method returns the number of skipped bytes.
+    #[inline]
+    pub fn skip_while<F>(&mut self, cnd: F) -> usize
+    where
+        F: FnMut(u8) -> bool,
+    {
+        self.read_while(cnd).len()
+    }
+
+    /// Read one word delimited by ASCII whitespace. The method skips all
+    /// initial whitespace (if any).
+    #[inline]
+    pub fn read_word(&mut self) -> &'a [u8] {
+        self.skip_whitespace();
+        self.read_until(|b| b.is_ascii_whitespace())
+    }
+
+    /// Read the next word and parse it. The word must be valid UTF-8. The
+    /// input won't be advanced if the word cannot be parsed.
+    ///
+    /// Unlike `StringReader::parse_word()`, the method does not return the
+    /// `FromStr` error because the word may not be valid UTF-8 in the first
+    /// place. Both cases are reported as `ParseError` instead.
+    pub fn parse_word<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+    {
+        let mut tmp = self.clone();
+
+        tmp.skip_whitespace();
+
+        let position = tmp.position;
+
+        let word = str::from_utf8(tmp.read_word()).map_err(|err| Self::utf8_error(&tmp, err))?;
+
+        let parsed = crate::parse::parse_value(word, position, "word")?;
+
+        *self = tmp;
+
+        Ok(parsed)
+    }
+
+    /// Read a decimal integer starting at the current position. See
+    /// `StringReader::read_int_inline()` for more info.
+    pub fn read_int_inline<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        let literal = self.ascii_prefix();
+
+        let len = num::int_literal_len(literal, &Default::default());
+
+        let parsed = num::parse_int(&literal[..len], &Default::default())
+            .map_err(|err| self.invalid_number("integer", (&err).into()))?;
+
+        self.split_to(len);
+
+        Ok(parsed)
+    }
+
+    /// Read a floating point number starting at the current position. See
+    /// `StringReader::read_float_inline()` for more info.
+    pub fn read_float_inline<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableFloat,
+    {
+        let literal = self.ascii_prefix();
+
+        let len = num::float_literal_len(literal, &Default::default());
+
+        let parsed = num::parse_float(&literal[..len], &Default::default())
+            .map_err(|err| self.invalid_number("floating point number", (&err).into()))?;
+
+        self.split_to(len);
+
+        Ok(parsed)
+    }
+
+    /// Validate the next `len` bytes as UTF-8 and return a string reader for
+    /// them. The string reader starts at the current position. An error is
+    /// returned if there are fewer bytes left or if the bytes are not valid
+    /// UTF-8. The input won't be advanced in case of an error.
+    pub fn read_utf8(&mut self, len: usize) -> Result<StringReader<'a>, ParseError> {
+        if len > self.input.len() {
+            let mut position = self.position;
+
+            position.advance_bytes(self.input);
+
+            let err = ParseError::new(ErrorKind::EmptyInput, position)
+                .with_expected(Expected::Description("more bytes"));
+
+            return Err(err);
+        }
+
+        let s = str::from_utf8(&self.input[..len]).map_err(|err| Self::utf8_error(self, err))?;
+
+        let position = self.position;
+
+        self.split_to(len);
+
+        Ok(StringReader::new(s).with_position(position))
+    }
+
+    /// Validate the remaining input as UTF-8 and convert the reader into a
+    /// string reader.
+    pub fn into_string_reader(mut self) -> Result<StringReader<'a>, ParseError> {
+        self.read_utf8(self.input.len())
+    }
+
+    /// Check if the reader is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Get the remaining input.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.input
+    }
+
+    /// Get the longest ASCII graphic prefix of the input.
+    fn ascii_prefix(&self) -> &'a str {
+        let len = self
+            .input
+            .iter()
+            .position(|b| !b.is_ascii_graphic())
+            .unwrap_or(self.input.len());
+
+        // ASCII is always valid UTF-8.
+        str::from_utf8(&self.input[..len]).unwrap_or("")
+    }
+
+    /// Split the input at a given index and return the first part.
+    fn split_to(&mut self, index: usize) -> &'a [u8] {
+        let (res, rest) = self.input.split_at(index);
+
+        self.position.advance_bytes(res);
+
+        self.input = rest;
+
+        res
+    }
+
+    /// Create an error of a given kind at the current position.
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError::new(kind, self.position)
+    }
+
+    /// Create an error for an invalid number at the current position with a
+    /// given cause.
+    fn invalid_number(&self, expected: &'static str, err: NumberError) -> ParseError {
+        let kind = if self.input.is_empty() {
+            ErrorKind::EmptyInput
+        } else {
+            ErrorKind::InvalidNumber
+        };
+
+        self.error(kind)
+            .with_expected(Expected::Description(expected))
+            .with_found_byte(self.current_byte())
+            .with_number_error(err)
+    }
+
+    /// Create an error for invalid UTF-8 found in the input of a given
+    /// reader.
+    fn utf8_error(reader: &Self, err: str::Utf8Error) -> ParseError {
+        let mut position = reader.position;
+
+        let index = err.valid_up_to();
+
+        position.advance_bytes(&reader.input[..index]);
+
+        ParseError::new(ErrorKind::InvalidUtf8, position)
+            .with_expected(Expected::Description("UTF-8"))
+            .with_found_byte(reader.input.get(index).copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::IntErrorKind;
+
+    use super::BytesReader;
+
+    use crate::{ErrorKind, Expected, NumberError};
+
+    #[test]
+    fn test_bytes_reader() {
+        let mut reader = BytesReader::new(b"GET /x\xff 12 -3.5 ok\n\xc3\xa9t\xe9");
+
+        assert_eq!(reader.read_word(), b"GET");
+        assert_eq!(reader.read_byte(), Ok(b' '));
+        assert_eq!(reader.read_until(|b| b == b' '), b"/x\xff");
+        assert_eq!(reader.parse_word::<u8>(), Ok(12));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_float_inline::<f32>(), Ok(-3.5));
+
+        let err = reader.read_int_inline::<u8>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::Empty))
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid number at 1:16: expected integer, found b' '"
+        );
+
+        let err = reader.match_bytes(b" oh").unwrap_err();
+
+        assert_eq!(err.position().offset, 17);
+        assert_eq!(err.expected(), Some(&Expected::Byte(b'h')));
+        assert_eq!(err.found_byte(), Some(b'k'));
+
+        reader.match_bytes(b" ok\n").unwrap();
+
+        assert_eq!(reader.position().line, 2);
+
+        let err = reader.clone().into_string_reader().err().unwrap();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidUtf8);
+        assert_eq!(err.position().column, 4);
+        assert_eq!(
+            err.to_string(),
+            "invalid UTF-8 at 2:4: expected UTF-8, found b'\\xe9'"
+        );
+
+        let err = reader.read_utf8(5).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().column, 5);
+        assert_eq!(reader.as_bytes(), b"\xc3\xa9t\xe9");
+
+        let mut s = reader.read_utf8(3).unwrap();
+
+        assert_eq!(s.position().line, 2);
+        assert_eq!(s.read_char(), Ok('é'));
+        assert_eq!(s.as_str(), "t");
+        assert_eq!(reader.as_bytes(), b"\xe9");
+
+        let err = reader.match_byte(b'x').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+
+        reader.skip_byte();
+
+        assert!(reader.is_empty());
+        assert_eq!(
+            reader.read_byte().unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+    }
+}
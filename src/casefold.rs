@@ -0,0 +1,103 @@
+use caseless::Caseless;
+
+use crate::{ErrorKind, Expected, InlineStr, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Match a given string to the input using full Unicode case folding
+    /// and, if successful, advance the input past the matched part. Unlike
+    /// `match_str_ignore_case()`, the matched part may differ in length from
+    /// the given string (e.g. `ß` matches `SS`). The default
+    /// (locale-independent) case folding is used. An error is returned if the
+    /// input does not start with the given string. The error will point to
+    /// the first character that does not match. The input won't be advanced
+    /// in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - expected string
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("STRASSE 5");
+    ///
+    /// reader.match_str_casefold("Straße").unwrap();
+    ///
+    /// assert_eq!(reader.as_str(), " 5");
+    /// ```
+    pub fn match_str_casefold(&mut self, val: &str) -> Result<(), ParseError> {
+        let input = self.as_str();
+
+        let mut expected = val.chars().default_case_fold().peekable();
+
+        let mut end = 0;
+
+        for (index, c) in input.char_indices() {
+            if expected.peek().is_none() {
+                break;
+            }
+
+            let matches = core::iter::once(c)
+                .default_case_fold()
+                .all(|folded| expected.next() == Some(folded));
+
+            if !matches {
+                let rest = &input[index..];
+
+                let err = ParseError::new(ErrorKind::NoMatch, self.position_of(rest))
+                    .with_expected(Expected::Str(InlineStr::new(val)))
+                    .with_found(Some(c));
+
+                return self.track(Err(err));
+            }
+
+            end = index + c.len_utf8();
+        }
+
+        if expected.peek().is_some() {
+            let err = ParseError::new(ErrorKind::NoMatch, self.end_position())
+                .with_expected(Expected::Str(InlineStr::new(val)));
+
+            return self.track(Err(err));
+        }
+
+        self.split_to(end);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_match_str_casefold() {
+        let mut reader = StringReader::new("Maße ﬁle ΣΑΣ strasse");
+
+        reader.match_str_casefold("MASSE").unwrap();
+        reader.match_str_casefold(" FI").unwrap();
+        reader.match_str_casefold("le σας ").unwrap();
+
+        let err = reader.match_str_casefold("straßx").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('e'));
+        assert_eq!(err.position().column, 20);
+        assert_eq!(reader.as_str(), "strasse");
+
+        // the expected string cannot end in the middle of a folded character
+        assert!(StringReader::new("ß").match_str_casefold("s").is_err());
+
+        let err = reader.match_str_casefold("strasse!").unwrap_err();
+
+        assert_eq!(err.found(), None);
+        assert_eq!(err.position().column, 21);
+
+        reader.match_str_casefold("STRAßE").unwrap();
+
+        assert!(reader.is_empty());
+    }
+}
@@ -0,0 +1,388 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    result,
+};
+
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::{ErrorKind, Expected, InlineStr, ParseError, Position, StringReader};
+
+/// Reader over multiple string segments.
+///
+/// The reader behaves as if the segments were concatenated but it does not
+/// allocate. Because the input is not contiguous, the reading methods return
+/// `ChainedStr` instead of `&str`. Only a subset of the `StringReader` API is
+/// available.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::StringReader;
+///
+/// let mut reader = StringReader::chained(&["HTTP/", "1.", "1 200 O", "K"]);
+///
+/// reader.match_str("HTTP/1.1").unwrap();
+///
+/// assert_eq!(reader.read_word(), "200");
+/// assert_eq!(reader.read_word(), "OK");
+/// assert!(reader.is_empty());
+/// ```
+#[derive(Copy, Clone)]
+pub struct ChainedReader<'s, 'a> {
+    segments: &'s [&'a str],
+    index: usize,
+    rest: &'a str,
+    position: Position,
+}
+
+impl<'a> StringReader<'a> {
+    /// Create a reader over multiple string segments. See `ChainedReader`
+    /// for more info.
+    #[inline]
+    pub fn chained<'s>(segments: &'s [&'a str]) -> ChainedReader<'s, 'a> {
+        ChainedReader::new(segments)
+    }
+}
+
+impl<'s, 'a> ChainedReader<'s, 'a> {
+    /// Create a new reader over given segments.
+    pub fn new(segments: &'s [&'a str]) -> Self {
+        let mut res = Self {
+            segments,
+            index: 0,
+            rest: segments.first().copied().unwrap_or(""),
+            position: Position::START,
+        };
+
+        res.skip_empty_segments();
+        res
+    }
+
+    /// Get the current character (if any) without advancing the input.
+    #[inline]
+    pub fn current_char(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// Get the current position.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Read the next character.
+    pub fn read_char(&mut self) -> Result<char, ParseError> {
+        let c = self.current_char().ok_or_else(|| {
+            ParseError::new(ErrorKind::EmptyInput, self.position)
+                .with_expected(Expected::Description("character"))
+        })?;
+
+        self.skip_char();
+
+        Ok(c)
+    }
+
+    /// Skip the next character.
+    pub fn skip_char(&mut self) {
+        if let Some(c) = self.current_char() {
+            self.rest = &self.rest[c.len_utf8()..];
+            self.position.advance_char(c);
+            self.skip_empty_segments();
+        }
+    }
+
+    /// Match the next character to a given one. The input won't be advanced
+    /// in case of an error.
+    pub fn match_char(&mut self, expected: char) -> Result<(), ParseError> {
+        let mut buf = [0u8; 4];
+
+        self.match_str(expected.encode_utf8(&mut buf))
+            .map_err(|err| err.with_expected(Expected::Char(expected)))
+    }
+
+    /// Match a given string to the input. The input won't be advanced in
+    /// case of an error. The error points at the first character that does
+    /// not match.
+    pub fn match_str(&mut self, val: &str) -> Result<(), ParseError> {
+        let mut tmp = *self;
+
+        for expected in val.chars() {
+            let kind = match tmp.current_char() {
+                Some(c) if c == expected => {
+                    tmp.skip_char();
+                    continue;
+                }
+                Some(_) => ErrorKind::NoMatch,
+                None => ErrorKind::EmptyInput,
+            };
+
+            let err = ParseError::new(kind, tmp.position)
+                .with_expected(Expected::Str(InlineStr::new(val)))
+                .with_found(tmp.current_char());
+
+            return Err(err);
+        }
+
+        *self = tmp;
+
+        Ok(())
+    }
+
+    /// Skip all whitespace characters.
+    #[inline]
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(char::is_whitespace);
+    }
+
+    /// Skip while a given condition is true or until the end of the input.
+    /// The method returns the number of skipped characters.
+    #[inline]
+    pub fn skip_while<F>(&mut self, cnd: F) -> usize
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.read_while(cnd).chars().count()
+    }
+
+    /// Read until a given condition is true or until the end of the input.
+    pub fn read_until<F>(&mut self, mut cnd: F) -> ChainedStr<'s, 'a>
+    where
+        F: FnMut(char) -> bool,
+    {
+        let start = self.location();
+
+        while let Some(c) = self.current_char() {
+            if cnd(c) {
+                break;
+            }
+
+            self.skip_char();
+        }
+
+        ChainedStr {
+            segments: self.segments,
+            start,
+            end: self.location(),
+        }
+    }
+
+    /// Read while a given condition is true or until the end of the input.
+    #[inline]
+    pub fn read_while<F>(&mut self, mut cnd: F) -> ChainedStr<'s, 'a>
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.read_until(|c| !cnd(c))
+    }
+
+    /// Read one word from the input. A word ends with the first whitespace
+    /// character or with the end of the input. The method skips all initial
+    /// whitespace characters (if any).
+    #[inline]
+    pub fn read_word(&mut self) -> ChainedStr<'s, 'a> {
+        self.skip_whitespace();
+        self.read_until(char::is_whitespace)
+    }
+
+    /// Read the next word and parse it. Words spanning multiple segments are
+    /// copied into a temporary string. The input won't be advanced if the
+    /// word cannot be parsed.
+    #[cfg(feature = "alloc")]
+    pub fn parse_word<T>(&mut self) -> Result<T, T::Err>
+    where
+        T: FromStr,
+    {
+        let mut tmp = *self;
+
+        let parsed = tmp.read_word().to_cow().parse()?;
+
+        *self = tmp;
+
+        Ok(parsed)
+    }
+
+    /// Check if the reader is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Get the current location (segment index and byte offset within the
+    /// segment).
+    fn location(&self) -> (usize, usize) {
+        let offset = self
+            .segments
+            .get(self.index)
+            .map(|s| s.len() - self.rest.len())
+            .unwrap_or(0);
+
+        (self.index, offset)
+    }
+
+    /// Move to the next non-empty segment if the current one is exhausted.
+    fn skip_empty_segments(&mut self) {
+        while self.rest.is_empty() && self.index < self.segments.len() {
+            self.index += 1;
+            self.rest = self.segments.get(self.index).copied().unwrap_or("");
+        }
+    }
+}
+
+/// String that may span multiple segments of a `ChainedReader` input.
+#[derive(Copy, Clone)]
+pub struct ChainedStr<'s, 'a> {
+    segments: &'s [&'a str],
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl<'s, 'a> ChainedStr<'s, 'a> {
+    /// Get the string pieces (one per segment).
+    pub fn pieces(&self) -> impl Iterator<Item = &'a str> + 's {
+        let (start, end) = (self.start, self.end);
+
+        self.segments
+            .iter()
+            .enumerate()
+            .take(end.0 + 1)
+            .skip(start.0)
+            .map(move |(index, segment)| {
+                let from = if index == start.0 { start.1 } else { 0 };
+                let to = if index == end.0 { end.1 } else { segment.len() };
+
+                &segment[from..to]
+            })
+            .filter(|piece| !piece.is_empty())
+    }
+
+    /// Get the characters of the string.
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + 's {
+        self.pieces().flat_map(str::chars)
+    }
+
+    /// Get the string if it is contained within a single segment.
+    pub fn as_str(&self) -> Option<&'a str> {
+        let mut pieces = self.pieces();
+
+        match (pieces.next(), pieces.next()) {
+            (None, _) => Some(""),
+            (Some(piece), None) => Some(piece),
+            _ => None,
+        }
+    }
+
+    /// Get the string, copying it only if it spans multiple segments.
+    #[cfg(feature = "alloc")]
+    pub fn to_cow(&self) -> Cow<'a, str> {
+        match self.as_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(self.pieces().collect::<String>()),
+        }
+    }
+
+    /// Get the length of the string in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pieces().map(str::len).sum()
+    }
+
+    /// Check if the string is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pieces().next().is_none()
+    }
+}
+
+impl PartialEq<str> for ChainedStr<'_, '_> {
+    fn eq(&self, other: &str) -> bool {
+        let mut other = other;
+
+        for piece in self.pieces() {
+            match other.strip_prefix(piece) {
+                Some(rest) => other = rest,
+                None => return false,
+            }
+        }
+
+        other.is_empty()
+    }
+}
+
+impl PartialEq<&str> for ChainedStr<'_, '_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Display for ChainedStr<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        for piece in self.pieces() {
+            f.write_str(piece)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for ChainedStr<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        f.write_str("\"")?;
+
+        for piece in self.pieces() {
+            write!(f, "{}", piece.escape_debug())?;
+        }
+
+        f.write_str("\"")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_chained_reader() {
+        let segments = ["", "GET /fo", "", "o/b", "ař HT", "TP/1.1\n"];
+
+        let mut reader = StringReader::chained(&segments);
+
+        reader.match_str("GET").unwrap();
+
+        let path = reader.read_word();
+
+        assert_eq!(path, "/foo/bař");
+        assert_eq!(path.as_str(), None);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.to_string(), "/foo/bař");
+        assert_eq!(path.pieces().collect::<Vec<_>>(), ["/fo", "o/b", "ař"]);
+
+        let err = reader.match_str(" HTTP/2").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.position().column, 19);
+        assert_eq!(err.found(), Some('1'));
+
+        reader.match_str(" HTTP/").unwrap();
+
+        assert_eq!(reader.parse_word::<f32>(), Ok(1.1));
+        assert_eq!(reader.read_char(), Ok('\n'));
+        assert_eq!(reader.position().line, 2);
+        assert!(reader.is_empty());
+        assert_eq!(reader.read_word().as_str(), Some(""));
+        assert_eq!(
+            reader.match_char('x').unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+
+        let mut reader = StringReader::chained(&[]);
+
+        assert!(reader.is_empty());
+        assert!(reader.read_char().is_err());
+    }
+}
@@ -0,0 +1,283 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::StringReader;
+
+/// A set of alternative parsing functions.
+///
+/// The trait is implemented for tuples of up to eight parsing functions. See
+/// `StringReader::alt()` for more info.
+pub trait Alt<'a, T, E> {
+    /// Try all alternatives in order and return the first successful result.
+    fn choice(&mut self, reader: &mut StringReader<'a>) -> Result<T, E>;
+}
+
+macro_rules! impl_alt {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<'a, T, E, $first $(, $rest)*> Alt<'a, T, E> for ($first, $($rest,)*)
+        where
+            $first: FnMut(&mut StringReader<'a>) -> Result<T, E>,
+            $($rest: FnMut(&mut StringReader<'a>) -> Result<T, E>),*
+        {
+            #[allow(non_snake_case)]
+            fn choice(&mut self, reader: &mut StringReader<'a>) -> Result<T, E> {
+                let ($first, $($rest,)*) = self;
+
+                let res = reader.transaction(|r| $first(r));
+
+                $(
+                    let res = match res {
+                        Ok(res) => return Ok(res),
+                        Err(_) => reader.transaction(|r| $rest(r)),
+                    };
+                )*
+
+                res
+            }
+        }
+    };
+}
+
+impl_alt!(A);
+impl_alt!(A, B);
+impl_alt!(A, B, C);
+impl_alt!(A, B, C, D);
+impl_alt!(A, B, C, D, F);
+impl_alt!(A, B, C, D, F, G);
+impl_alt!(A, B, C, D, F, G, H);
+impl_alt!(A, B, C, D, F, G, H, I);
+
+impl<'a> StringReader<'a> {
+    /// Try given alternative parsing functions in order and return the result
+    /// of the first successful one. The reader is rewound to its original
+    /// state after each failed attempt. The error of the last alternative is
+    /// returned if all alternatives fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `alternatives` - a tuple of parsing functions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("0x1f");
+    ///
+    /// let res = reader.alt((
+    ///     |r: &mut StringReader| r.match_str("0o").map(|_| 8),
+    ///     |r: &mut StringReader| r.match_str("0x").map(|_| 16),
+    ///     |r: &mut StringReader| r.match_str("0b").map(|_| 2),
+    /// ));
+    ///
+    /// assert_eq!(res, Ok(16));
+    /// assert_eq!(reader.as_str(), "1f");
+    /// ```
+    #[inline]
+    pub fn alt<A, T, E>(&mut self, mut alternatives: A) -> Result<T, E>
+    where
+        A: Alt<'a, T, E>,
+    {
+        alternatives.choice(self)
+    }
+
+    /// Try a given parsing function and return its result or `None` if the
+    /// function fails. The reader is rewound to its original state in case of
+    /// a failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - parsing function
+    #[inline]
+    pub fn opt<F, T, E>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        self.transaction(f).ok()
+    }
+
+    /// Apply a given parsing function repeatedly until it fails and return
+    /// all results. The reader is rewound to the state after the last
+    /// successful application. The repetition also stops if the function does
+    /// not consume any input.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - parsing function
+    #[cfg(feature = "alloc")]
+    pub fn many0<F, T, E>(&mut self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let mut res = Vec::new();
+
+        loop {
+            let offset = self.position.offset;
+
+            match self.transaction(&mut f) {
+                Ok(item) => res.push(item),
+                Err(_) => break,
+            }
+
+            if self.position.offset == offset {
+                break;
+            }
+        }
+
+        res
+    }
+
+    /// Apply a given parsing function repeatedly until it fails and return
+    /// all results. Unlike `many0()`, the function must succeed at least once.
+    /// The error of the first application is returned otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - parsing function
+    #[cfg(feature = "alloc")]
+    pub fn many1<F, T, E>(&mut self, mut f: F) -> Result<Vec<T>, E>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let offset = self.position.offset;
+
+        let first = self.transaction(&mut f)?;
+
+        let mut res = vec![first];
+
+        if self.position.offset != offset {
+            res.extend(self.many0(f));
+        }
+
+        Ok(res)
+    }
+
+    /// Parse a list of items separated by a given separator. The list may be
+    /// empty. Parsing stops at the first item or separator that cannot be
+    /// parsed. The reader is rewound to the state after the last successfully
+    /// parsed item (i.e. a trailing separator is not consumed).
+    ///
+    /// # Arguments
+    ///
+    /// * `sep` - separator parsing function
+    /// * `f` - item parsing function
+    #[cfg(feature = "alloc")]
+    pub fn separated_list<S, F, U, T, E, SE>(&mut self, mut sep: S, mut f: F) -> Vec<T>
+    where
+        S: FnMut(&mut Self) -> Result<U, SE>,
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        let mut res = Vec::new();
+
+        if let Ok(item) = self.transaction(&mut f) {
+            res.push(item);
+        } else {
+            return res;
+        }
+
+        loop {
+            let item = self.transaction(|reader| {
+                sep(reader).map_err(|_| ())?;
+                f(reader).map_err(|_| ())
+            });
+
+            match item {
+                Ok(item) => res.push(item),
+                Err(()) => break,
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StringReader;
+
+    #[derive(Debug, PartialEq)]
+    enum Value<'a> {
+        Bool(bool),
+        Number(u32),
+        Ident(&'a str),
+    }
+
+    fn value<'a>(reader: &mut StringReader<'a>) -> Result<Value<'a>, ()> {
+        reader.alt((
+            |r: &mut StringReader<'a>| {
+                r.match_keyword("true").map_err(|_| ())?;
+                Ok(Value::Bool(true))
+            },
+            |r: &mut StringReader<'a>| {
+                r.match_keyword("false").map_err(|_| ())?;
+                Ok(Value::Bool(false))
+            },
+            |r: &mut StringReader<'a>| r.read_int_inline().map(Value::Number).map_err(|_| ()),
+            |r: &mut StringReader<'a>| r.read_identifier().map(Value::Ident).map_err(|_| ()),
+        ))
+    }
+
+    #[test]
+    fn test_alt() {
+        let mut reader = StringReader::new("true 12 falsey false +");
+
+        let mut values = Vec::new();
+
+        while !reader.is_empty() {
+            reader.skip_whitespace();
+
+            match value(&mut reader) {
+                Ok(v) => values.push(v),
+                Err(_) => break,
+            }
+        }
+
+        assert_eq!(
+            values,
+            [
+                Value::Bool(true),
+                Value::Number(12),
+                Value::Ident("falsey"),
+                Value::Bool(false),
+            ]
+        );
+
+        assert_eq!(reader.as_str(), "+");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_repetition() {
+        let mut reader = StringReader::new("aaab 1, 2,3 ,x");
+
+        assert_eq!(reader.opt(|r| r.match_char('b')), None);
+        assert_eq!(reader.many0(|r| r.match_char('a')).len(), 3);
+        assert_eq!(reader.many0(|r| r.match_char('a')).len(), 0);
+        assert!(reader.many1(|r| r.match_char('a')).is_err());
+        assert_eq!(reader.many1(|r| r.match_char('b')).map(|v| v.len()), Ok(1));
+        assert_eq!(reader.opt(|r| r.match_char(' ')), Some(()));
+
+        let items = reader.separated_list(
+            |r| {
+                r.skip_whitespace();
+                r.match_char(',')
+            },
+            |r| {
+                r.skip_whitespace();
+                r.read_int_inline::<u32>()
+            },
+        );
+
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(reader.as_str(), " ,x");
+
+        let empty = reader.many0(|r| r.read_word().parse::<u32>());
+
+        assert!(empty.is_empty());
+        assert_eq!(reader.as_str(), " ,x");
+
+        let none = reader.separated_list(|r| r.match_char(','), |r| r.match_char('y'));
+
+        assert!(none.is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{Delimiters, ErrorKind, Expected, InlineStr, ParseError, StringReader};
+
+/// Comment syntax definition.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{Comments, StringReader};
+///
+/// let comments = Comments::new()
+///     .line_comments(&["//", "#"])
+///     .block_comments("/*", "*/");
+///
+/// let mut reader = StringReader::new("# comment\n/* block */ foo").with_comments(comments);
+///
+/// assert_eq!(reader.read_word(), "foo");
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Comments {
+    line: &'static [&'static str],
+    block: Option<(&'static str, &'static str)>,
+    nested: bool,
+}
+
+impl Comments {
+    /// Create a new comment syntax definition with no comments.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            line: &[],
+            block: None,
+            nested: false,
+        }
+    }
+
+    /// Set line comment markers (e.g. `//`, `#` or `;`). A line comment
+    /// extends up to the end of the line. The line terminator is not
+    /// considered part of the comment.
+    #[inline]
+    pub const fn line_comments(mut self, markers: &'static [&'static str]) -> Self {
+        self.line = markers;
+        self
+    }
+
+    /// Set block comment markers (e.g. `/*` and `*/`).
+    #[inline]
+    pub const fn block_comments(mut self, open: &'static str, close: &'static str) -> Self {
+        self.block = Some((open, close));
+        self
+    }
+
+    /// Allow/disallow nesting of block comments (disallowed by default).
+    #[inline]
+    pub const fn nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
+
+    /// Skip all leading delimiters and comments in a given string. In case of
+    /// an unterminated block comment, the error contains the input starting
+    /// with the comment.
+    pub(crate) fn trim_start<'a>(
+        &self,
+        mut s: &'a str,
+        delimiters: &Delimiters,
+    ) -> Result<&'a str, &'a str> {
+        loop {
+            s = delimiters.trim_start(s);
+
+            if let Some(marker) = self.line.iter().find(|m| s.starts_with(*m)) {
+                let comment = &s[marker.len()..];
+
+                s = &comment[comment.find('\n').unwrap_or(comment.len())..];
+            } else if let Some((open, close)) = self.block.filter(|(open, _)| s.starts_with(open)) {
+                s = self.skip_block_comment(s, open, close).ok_or(s)?;
+            } else {
+                return Ok(s);
+            }
+        }
+    }
+
+    /// Skip a block comment at the beginning of a given string.
+    fn skip_block_comment<'a>(&self, s: &'a str, open: &str, close: &str) -> Option<&'a str> {
+        let mut rest = &s[open.len()..];
+
+        if !self.nested {
+            let index = rest.find(close)?;
+
+            return Some(&rest[index + close.len()..]);
+        }
+
+        let mut depth = 1usize;
+
+        while depth > 0 {
+            let next_close = rest.find(close)?;
+
+            match rest[..next_close].find(open) {
+                Some(next_open) => {
+                    depth += 1;
+                    rest = &rest[next_open + open.len()..];
+                }
+                None => {
+                    depth -= 1;
+                    rest = &rest[next_close + close.len()..];
+                }
+            }
+        }
+
+        Some(rest)
+    }
+
+    /// Get the closing marker of block comments.
+    fn block_close(&self) -> &'static str {
+        self.block.map(|(_, close)| close).unwrap_or("")
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Use a given comment syntax. Comments will be skipped together with
+    /// whitespace by `skip_whitespace()` and by all word-based methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `comments` - comment syntax definition
+    #[inline]
+    pub fn with_comments(mut self, comments: Comments) -> Self {
+        self.comments = Some(comments);
+        self
+    }
+
+    /// Skip all whitespace characters (word delimiters) and comments. Unlike
+    /// `skip_whitespace()`, this method returns an error if there is an
+    /// unterminated block comment. The input will be advanced up to the
+    /// beginning of the comment in such case.
+    pub fn skip_comments(&mut self) -> Result<(), ParseError> {
+        let comments = self.comments.unwrap_or_default();
+
+        match comments.trim_start(self.input.as_str(), &self.delimiters) {
+            Ok(rest) => {
+                self.advance_to(rest);
+
+                Ok(())
+            }
+            Err(rest) => {
+                self.advance_to(rest);
+
+                let close = InlineStr::new(comments.block_close());
+
+                let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                    .with_expected(Expected::Str(close));
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Skip all leading whitespace characters (word delimiters) and comments
+    /// in a given string. Unterminated block comments are not skipped.
+    pub(crate) fn trim_start(&self, s: &'a str) -> &'a str {
+        if let Some(comments) = self.comments.as_ref() {
+            comments
+                .trim_start(s, &self.delimiters)
+                .unwrap_or_else(|rest| rest)
+        } else {
+            self.delimiters.trim_start(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Comments, ErrorKind, StringReader};
+
+    #[test]
+    fn test_comments() {
+        let comments = Comments::new()
+            .line_comments(&["//", ";"])
+            .block_comments("/*", "*/");
+
+        let input = "  // line\n; other\r\n /* a /* b */ 1 /**/2 // end";
+
+        let mut reader = StringReader::new(input).with_comments(comments);
+
+        assert_eq!(reader.read_u32(), Ok(1));
+        assert_eq!(reader.read_word(), "2");
+
+        reader.skip_whitespace();
+
+        assert!(reader.is_empty());
+
+        let mut reader = StringReader::new(input).with_comments(comments.nested(true));
+
+        assert!(reader.skip_comments().is_err());
+        assert!(reader.as_str().starts_with("/* a /* b */"));
+
+        let mut reader = StringReader::new("/* a */ /* unterminated").with_comments(comments);
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.as_str(), "/* unterminated");
+
+        let err = reader.skip_comments().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, 23);
+        assert_eq!(reader.as_str(), "/* unterminated");
+
+        let mut reader = StringReader::new("/* /* */ */ x").with_comments(comments.nested(true));
+
+        assert_eq!(reader.read_word(), "x");
+    }
+}
@@ -0,0 +1,242 @@
+use core::iter::FusedIterator;
+
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Read a CSV field according to RFC 4180.
+    ///
+    /// A field is either a bare value ending with a given delimiter, a line
+    /// break or the end of the input, or a value enclosed in double quotes.
+    /// Quoted values may contain delimiters, line breaks and double quotes
+    /// (escaped by doubling them). The closing quote must be followed by the
+    /// delimiter, a line break or the end of the input. The terminating
+    /// delimiter or line break is not consumed. The returned string borrows
+    /// the input if there are no escaped quotes. The input won't be advanced
+    /// in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - field delimiter
+    pub fn read_csv_field(&mut self, delimiter: char) -> Result<Cow<'a, str>, ParseError> {
+        if self.current_char() != Some('"') {
+            let field = self.read_until(|c| c == delimiter || c == '\n' || c == '\r');
+
+            return Ok(Cow::Borrowed(field));
+        }
+
+        self.transaction(|reader| {
+            reader.skip_char();
+
+            let mut res = Cow::Borrowed(reader.read_until_char('"'));
+
+            loop {
+                if reader.is_empty() {
+                    let err = reader
+                        .error(ErrorKind::EmptyInput)
+                        .with_expected(Expected::Char('"'));
+
+                    return Err(err);
+                }
+
+                reader.skip_char();
+
+                if reader.current_char() == Some('"') {
+                    reader.skip_char();
+
+                    let s = reader.read_until_char('"');
+
+                    let owned = res.to_mut();
+
+                    owned.push('"');
+                    owned.push_str(s);
+                } else {
+                    break;
+                }
+            }
+
+            match reader.current_char() {
+                None => Ok(res),
+                Some(c) if c == delimiter || c == '\n' || reader.starts_with("\r\n") => Ok(res),
+                c => {
+                    let err = reader
+                        .error(ErrorKind::NoMatch)
+                        .with_expected(Expected::Description("end of field"))
+                        .with_found(c);
+
+                    Err(err)
+                }
+            }
+        })
+    }
+
+    /// Read a CSV record (i.e. a line of fields separated by a given
+    /// delimiter). The line break terminating the record (`\n` or `\r\n`) is
+    /// consumed. See `read_csv_field()` for more info. The input won't be
+    /// advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - field delimiter
+    pub fn read_csv_record(&mut self, delimiter: char) -> Result<Vec<Cow<'a, str>>, ParseError> {
+        self.transaction(|reader| {
+            let mut res = Vec::new();
+
+            loop {
+                res.push(reader.read_csv_field(delimiter)?);
+
+                if reader.current_char() == Some(delimiter) {
+                    reader.skip_char();
+                } else if reader.is_empty()
+                    || reader.match_str("\r\n").is_ok()
+                    || reader.match_char('\n').is_ok()
+                {
+                    break;
+                } else {
+                    // a bare CR that is not part of a line break
+                    let err = reader
+                        .error(ErrorKind::NoMatch)
+                        .with_expected(Expected::Description("end of field"))
+                        .with_found(reader.current_char());
+
+                    return Err(err);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+
+    /// Get an iterator over the remaining CSV records. See
+    /// `read_csv_record()` for more info. The iterator stops after the first
+    /// record that cannot be read. The reader will be positioned at the
+    /// beginning of the record in such case.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - field delimiter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("name,note\r\nfoo,\"a, \"\"b\"\"\"\r\nbar,\r\n");
+    ///
+    /// let records = reader
+    ///     .csv_records(',')
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(records.len(), 3);
+    /// assert_eq!(records[1], ["foo", "a, \"b\""]);
+    /// assert_eq!(records[2], ["bar", ""]);
+    /// ```
+    #[inline]
+    pub fn csv_records(&mut self, delimiter: char) -> CsvRecords<'_, 'a> {
+        CsvRecords {
+            reader: self,
+            delimiter,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over CSV records.
+///
+/// The iterator is created by `StringReader::csv_records()`.
+pub struct CsvRecords<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+    delimiter: char,
+    done: bool,
+}
+
+impl<'a> Iterator for CsvRecords<'_, 'a> {
+    type Item = Result<Vec<Cow<'a, str>>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.reader.is_empty() {
+            return None;
+        }
+
+        let res = self.reader.read_csv_record(self.delimiter);
+
+        self.done = res.is_err();
+
+        Some(res)
+    }
+}
+
+impl FusedIterator for CsvRecords<'_, '_> {}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_csv_field() {
+        let mut reader = StringReader::new(r#"foo;"a;b";"say ""hi""";;"x"y"#);
+
+        assert!(matches!(
+            reader.read_csv_field(';'),
+            Ok(Cow::Borrowed("foo"))
+        ));
+
+        reader.skip_char();
+
+        assert!(matches!(
+            reader.read_csv_field(';'),
+            Ok(Cow::Borrowed("a;b"))
+        ));
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_csv_field(';').unwrap(), "say \"hi\"");
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_csv_field(';').unwrap(), "");
+
+        reader.skip_char();
+
+        let err = reader.read_csv_field(';').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('y'));
+        assert_eq!(reader.as_str(), "\"x\"y");
+
+        let mut reader = StringReader::new("\"unterminated\n");
+
+        let err = reader.read_csv_field(',').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().line, 2);
+    }
+
+    #[test]
+    fn test_csv_records() {
+        let mut reader = StringReader::new("a,\"multi\nline\"\n\n1,2,3\r\nbad,\"x\" \nnext");
+
+        let mut records = reader.csv_records(',');
+
+        assert_eq!(
+            records.next(),
+            Some(Ok(vec!["a".into(), "multi\nline".into()]))
+        );
+        assert_eq!(records.next(), Some(Ok(vec!["".into()])));
+        assert_eq!(
+            records.next(),
+            Some(Ok(vec!["1".into(), "2".into(), "3".into()]))
+        );
+        assert!(matches!(records.next(), Some(Err(_))));
+        assert_eq!(records.next(), None);
+        assert_eq!(reader.as_str(), "bad,\"x\" \nnext");
+
+        let mut reader = StringReader::new("a\rb");
+
+        assert!(reader.read_csv_record(',').is_err());
+    }
+}
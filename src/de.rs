@@ -0,0 +1,417 @@
+//! Serde deserializer backed by a string reader.
+//!
+//! The deserializer treats the input as a sequence of fields separated by
+//! delimiters (see `Delimiters`). Structs, tuples and sequences are read as
+//! consecutive fields, primitive values are parsed from single fields and
+//! unit enum variants are identified by their names. Optional values are
+//! `None` only if there are no more fields in the input. Maps are not
+//! supported.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::Deserialize;
+//! use str_reader::StringReader;
+//!
+//! #[derive(Deserialize)]
+//! struct Record<'a> {
+//!     name: &'a str,
+//!     size: u64,
+//!     tags: Vec<String>,
+//! }
+//!
+//! let mut reader = StringReader::new("foo 123 a b\nbar 456");
+//!
+//! let records = reader
+//!     .lines()
+//!     .map(|mut line| str_reader::de::from_reader(&mut line))
+//!     .collect::<Result<Vec<Record>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(records[0].name, "foo");
+//! assert_eq!(records[0].tags, ["a", "b"]);
+//! assert_eq!(records[1].size, 456);
+//! assert!(records[1].tags.is_empty());
+//! ```
+
+use core::{
+    fmt::{self, Display, Formatter},
+    result,
+};
+
+use alloc::string::{String, ToString};
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, SeqAccess, Visitor};
+
+use crate::{parse, ErrorKind, Expected, ParseError, StringReader};
+
+/// Deserialization error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input could not be parsed.
+    Parse(ParseError),
+    /// Custom error reported by the deserialized type.
+    Custom(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        match self {
+            Self::Parse(err) => Display::fmt(err, f),
+            Self::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl de::StdError for Error {}
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl From<ParseError> for Error {
+    #[inline]
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Deserialize a value from a given string. The whole input must be
+/// consumed.
+pub fn from_str<'a, T>(s: &'a str) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    let mut reader = StringReader::new(s);
+
+    let res = from_reader(&mut reader)?;
+
+    reader.skip_whitespace();
+
+    if reader.is_empty() {
+        Ok(res)
+    } else {
+        let err = reader
+            .error(ErrorKind::NoMatch)
+            .with_expected(Expected::Description("end of input"))
+            .with_found(reader.current_char());
+
+        Err(err.into())
+    }
+}
+
+/// Deserialize a value from a given reader. The reader will contain the
+/// remaining input.
+#[inline]
+pub fn from_reader<'a, T>(reader: &mut StringReader<'a>) -> Result<T, Error>
+where
+    T: de::Deserialize<'a>,
+{
+    T::deserialize(&mut Deserializer::new(reader))
+}
+
+/// Serde deserializer backed by a string reader.
+pub struct Deserializer<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+}
+
+impl<'r, 'a> Deserializer<'r, 'a> {
+    /// Create a new deserializer reading from a given reader.
+    #[inline]
+    pub fn new(reader: &'r mut StringReader<'a>) -> Self {
+        Self { reader }
+    }
+
+    /// Read the next field and parse it.
+    fn parse<T>(&mut self, expected: &'static str) -> Result<T, Error>
+    where
+        T: core::str::FromStr,
+    {
+        parse::parse_word(self.reader, expected).map_err(Error::from)
+    }
+
+    /// Read the next field.
+    fn field(&mut self) -> Result<&'a str, Error> {
+        self.reader.parse::<&str>().map_err(Error::from)
+    }
+
+    /// Check if there are no more fields.
+    fn is_empty(&mut self) -> bool {
+        let (word, _) = self.reader.first_word();
+
+        word.is_empty()
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $t:ty),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'a>,
+            {
+                visitor.$visit(self.parse::<$t>(stringify!($t))?)
+            }
+        )*
+    };
+}
+
+impl<'r, 'a> de::Deserializer<'a> for &mut Deserializer<'r, 'a> {
+    type Error = Error;
+
+    deserialize_parsed!(
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+        deserialize_char => visit_char: char,
+    );
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_borrowed_str(self.field()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_borrowed_bytes(self.field()?.as_bytes())
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        if self.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_seq(Fields {
+            de: self,
+            remaining: None,
+        })
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_seq(Fields {
+            de: self,
+            remaining: Some(len),
+        })
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V>(self, _: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        Err(de::Error::custom("maps are not supported"))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_enum(self.field()?.into_deserializer())
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'a>,
+    {
+        self.field()?;
+
+        visitor.visit_unit()
+    }
+}
+
+/// Access to consecutive fields.
+struct Fields<'d, 'r, 'a> {
+    de: &'d mut Deserializer<'r, 'a>,
+    remaining: Option<usize>,
+}
+
+impl<'d, 'r, 'a> SeqAccess<'a> for Fields<'d, 'r, 'a> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'a>,
+    {
+        match self.remaining.as_mut() {
+            Some(0) => return Ok(None),
+            Some(remaining) => *remaining -= 1,
+            None if self.de.is_empty() => return Ok(None),
+            None => (),
+        }
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::Error;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        Info,
+        Error,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Entry<'a> {
+        level: Level,
+        code: u16,
+        #[serde(borrow)]
+        source: (&'a str, char),
+        ratio: Option<f32>,
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let entry: Entry = super::from_str("error 404 foo x 0.5").unwrap();
+
+        assert_eq!(
+            entry,
+            Entry {
+                level: Level::Error,
+                code: 404,
+                source: ("foo", 'x'),
+                ratio: Some(0.5),
+            }
+        );
+
+        let entry: Entry = super::from_str(" info 200 bar y ").unwrap();
+
+        assert_eq!(entry.level, Level::Info);
+        assert_eq!(entry.ratio, None);
+
+        let mut reader = StringReader::new("1 2 3");
+
+        let (a, b): (u8, u8) = super::from_reader(&mut reader).unwrap();
+
+        assert_eq!((a, b), (1, 2));
+        assert_eq!(reader.as_str(), " 3");
+
+        match super::from_str::<Entry>("info 1x bar y") {
+            Err(Error::Parse(err)) => assert_eq!(err.kind(), ErrorKind::InvalidValue),
+            res => panic!("unexpected result: {:?}", res),
+        }
+
+        assert!(super::from_str::<Entry>("warning 200 bar y").is_err());
+        assert!(super::from_str::<(u8, u8)>("1 2 3").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+/// Definition of word delimiters.
+///
+/// Word delimiters are used by all word-based methods of the string reader
+/// (e.g. `read_word()`, `parse_word()` or `skip_whitespace()`).
+#[derive(Debug, Copy, Clone)]
+pub enum Delimiters {
+    /// Unicode whitespace characters (see `char::is_whitespace()`).
+    Whitespace,
+    /// ASCII space, horizontal tab, carriage return and line feed. This is
+    /// what most wire protocols consider to be whitespace.
+    AsciiWhitespace,
+    /// A custom set of delimiters defined by a given function.
+    Custom(fn(char) -> bool),
+}
+
+impl Delimiters {
+    /// Check if a given character is a delimiter.
+    #[inline]
+    pub fn is_delimiter(&self, c: char) -> bool {
+        match *self {
+            Self::Whitespace => c.is_whitespace(),
+            Self::AsciiWhitespace => c.is_ascii() && is_protocol_space(c as u8),
+            Self::Custom(f) => f(c),
+        }
+    }
+
+    /// Remove all leading delimiters from a given string.
+    #[inline]
+    pub(crate) fn trim_start<'a>(&self, s: &'a str) -> &'a str {
+        match *self {
+            Self::Whitespace => trim_start_whitespace(s),
+            Self::AsciiWhitespace => {
+                let index = s
+                    .bytes()
+                    .position(|b| !is_protocol_space(b))
+                    .unwrap_or(s.len());
+
+                &s[index..]
+            }
+            Self::Custom(f) => s.trim_start_matches(f),
+        }
+    }
+
+    /// Find the first delimiter in a given string.
+    #[inline]
+    pub(crate) fn find(&self, s: &str) -> Option<usize> {
+        match *self {
+            Self::Whitespace => find_whitespace(s),
+            Self::AsciiWhitespace => s.bytes().position(is_protocol_space),
+            Self::Custom(f) => s.find(f),
+        }
+    }
+}
+
+impl Default for Delimiters {
+    #[inline]
+    fn default() -> Self {
+        Self::Whitespace
+    }
+}
+
+/// Check if a given ASCII byte is a whitespace character according to
+/// `char::is_whitespace()`.
+#[inline]
+fn is_ascii_space(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | 0x0b | 0x0c | b'\r' | b' ')
+}
+
+/// Check if a given byte is an ASCII space, horizontal tab, carriage return
+/// or line feed.
+#[inline]
+fn is_protocol_space(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+/// Remove all leading Unicode whitespace. ASCII characters are checked
+/// byte-wise without decoding.
+fn trim_start_whitespace(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    let mut index = 0;
+
+    while let Some(&b) = bytes.get(index) {
+        if b.is_ascii() {
+            if !is_ascii_space(b) {
+                break;
+            }
+
+            index += 1;
+        } else {
+            return s[index..].trim_start();
+        }
+    }
+
+    &s[index..]
+}
+
+/// Find the first Unicode whitespace character. ASCII characters are checked
+/// byte-wise without decoding.
+fn find_whitespace(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+
+    let mut index = 0;
+
+    while let Some(&b) = bytes.get(index) {
+        if b.is_ascii() {
+            if is_ascii_space(b) {
+                return Some(index);
+            }
+
+            index += 1;
+        } else if let Some(c) = s[index..].chars().next() {
+            if c.is_whitespace() {
+                return Some(index);
+            }
+
+            index += c.len_utf8();
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Delimiters;
+
+    #[test]
+    fn test_whitespace() {
+        let delimiters = Delimiters::Whitespace;
+
+        assert_eq!(delimiters.trim_start(" \t\x0b\u{a0}\u{3000}x y"), "x y");
+        assert_eq!(delimiters.trim_start("\r\n"), "");
+        assert_eq!(delimiters.find("abc\x0cd"), Some(3));
+        assert_eq!(delimiters.find("žluť\u{2003}x"), Some(6));
+        assert_eq!(delimiters.find("kůň"), None);
+    }
+
+    #[test]
+    fn test_ascii_whitespace() {
+        let delimiters = Delimiters::AsciiWhitespace;
+
+        assert!(delimiters.is_delimiter('\t'));
+        assert!(!delimiters.is_delimiter('\u{a0}'));
+        assert!(!delimiters.is_delimiter('\x0c'));
+        assert_eq!(delimiters.trim_start(" \r\n\u{a0}x"), "\u{a0}x");
+        assert_eq!(delimiters.find("a\u{3000}b c"), Some(5));
+        assert_eq!(delimiters.find("abc"), None);
+    }
+}
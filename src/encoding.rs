@@ -0,0 +1,408 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Read pairs of hexadecimal digits starting at the current position and
+    /// write the decoded bytes into a given buffer. The method stops at the
+    /// first character that is not a hexadecimal digit or when the buffer is
+    /// full. The number of decoded bytes is returned. An error is returned
+    /// if the number of hexadecimal digits is odd. The input won't be
+    /// advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - output buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("00:1A:2b");
+    ///
+    /// let mut mac = [0u8; 3];
+    ///
+    /// for (index, b) in mac.iter_mut().enumerate() {
+    ///     if index > 0 {
+    ///         reader.match_char(':').unwrap();
+    ///     }
+    ///
+    ///     reader.read_hex_bytes(std::slice::from_mut(b)).unwrap();
+    /// }
+    ///
+    /// assert_eq!(mac, [0x00, 0x1a, 0x2b]);
+    /// ```
+    pub fn read_hex_bytes(&mut self, out: &mut [u8]) -> Result<usize, ParseError> {
+        let input = self.as_str().as_bytes();
+
+        let digits = input
+            .iter()
+            .take(out.len() * 2)
+            .take_while(|b| b.is_ascii_hexdigit())
+            .count();
+
+        if digits % 2 != 0 {
+            let rest = &self.as_str()[digits - 1..];
+
+            let err = ParseError::new(ErrorKind::InvalidValue, self.position_of(rest))
+                .with_expected(Expected::Description("pair of hexadecimal digits"))
+                .with_found(rest.chars().next());
+
+            return Err(err);
+        }
+
+        for (b, pair) in out.iter_mut().zip(input[..digits].chunks_exact(2)) {
+            *b = (hex_digit(pair[0]) << 4) | hex_digit(pair[1]);
+        }
+
+        self.split_to(digits);
+
+        Ok(digits / 2)
+    }
+
+    /// Read a base64-encoded string starting at the current position and
+    /// write the decoded bytes into a given buffer. The number of decoded
+    /// bytes is returned.
+    ///
+    /// Both the standard and the URL-safe alphabet are accepted but they
+    /// cannot be mixed. The alphabet is given by the first character specific
+    /// to one of them (`+` and `/` or `-` and `_`). Padding is
+    /// optional but if present, it must be complete. The method stops at the
+    /// first character that is not part of the string or before the first
+    /// group of four characters that would not fit into the buffer. The
+    /// input won't be advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - output buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("Basic dXNlcjpwYXNz");
+    ///
+    /// reader.match_str("Basic ").unwrap();
+    ///
+    /// let mut buf = [0u8; 64];
+    ///
+    /// let len = reader.read_base64(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], b"user:pass");
+    /// ```
+    pub fn read_base64(&mut self, out: &mut [u8]) -> Result<usize, ParseError> {
+        let input = self.as_str().as_bytes();
+
+        let data_len = input
+            .iter()
+            .take_while(|&&b| base64_digit(b).is_some())
+            .count();
+
+        let invalid = |index: usize| {
+            let rest = &self.as_str()[index..];
+
+            ParseError::new(ErrorKind::InvalidValue, self.position_of(rest))
+                .with_expected(Expected::Description("base64"))
+                .with_found(rest.chars().next())
+        };
+
+        let mut url_safe = None;
+
+        for (index, &b) in input[..data_len].iter().enumerate() {
+            let is_url_safe = match b {
+                b'+' | b'/' => false,
+                b'-' | b'_' => true,
+                _ => continue,
+            };
+
+            if *url_safe.get_or_insert(is_url_safe) != is_url_safe {
+                return Err(invalid(index));
+            }
+        }
+
+        if data_len % 4 == 1 {
+            return Err(invalid(data_len - 1));
+        }
+
+        let mut consumed = 0;
+        let mut written = 0;
+
+        for group in input[..data_len].chunks(4) {
+            let len = group.len() - 1;
+
+            if out.len() - written < len {
+                break;
+            }
+
+            let bits = group
+                .iter()
+                .map(|&b| u32::from(base64_digit(b).unwrap_or(0)))
+                .chain(core::iter::repeat(0))
+                .take(4)
+                .fold(0, |res, digit| (res << 6) | digit);
+
+            for (index, b) in out[written..written + len].iter_mut().enumerate() {
+                *b = (bits >> (16 - index * 8)) as u8;
+            }
+
+            consumed += group.len();
+            written += len;
+        }
+
+        if consumed == data_len && data_len % 4 != 0 {
+            let padding = input[data_len..]
+                .iter()
+                .take(2)
+                .take_while(|&&b| b == b'=')
+                .count();
+
+            if padding == 4 - data_len % 4 {
+                consumed += padding;
+            } else if padding > 0 {
+                return Err(invalid(data_len));
+            }
+        }
+
+        self.split_to(consumed);
+
+        Ok(written)
+    }
+
+    /// Read until a given condition is true or until the end of the input
+    /// and decode all percent-encoded bytes (`%XX`). The returned string
+    /// borrows the input if there are no percent-encoded bytes. Note that `+`
+    /// is not decoded as a space. The input won't be advanced in case of an
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// * `until` - condition terminating the encoded string
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("/caf%C3%A9%20bar?x=1");
+    ///
+    /// let path = reader.read_percent_decoded(|c| c == '?').unwrap();
+    ///
+    /// assert_eq!(path, "/café bar");
+    /// assert_eq!(reader.as_str(), "?x=1");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn read_percent_decoded<F>(&mut self, mut until: F) -> Result<Cow<'a, str>, ParseError>
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let s = reader.read_until(|c| c == '%' || until(c));
+
+            if reader.current_char() != Some('%') {
+                return Ok(Cow::Borrowed(s));
+            }
+
+            let mut res = Vec::from(s);
+
+            while reader.current_char() == Some('%') {
+                let digits = &reader.peek_str(3)[1..];
+
+                if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    let err = reader
+                        .error(ErrorKind::InvalidEscape)
+                        .with_expected(Expected::Description("percent-encoded byte"))
+                        .with_found(Some('%'));
+
+                    return Err(err);
+                }
+
+                res.push(u8::from_str_radix(digits, 16).unwrap_or(0));
+
+                reader.split_to(3);
+
+                let s = reader.read_until(|c| c == '%' || until(c));
+
+                res.extend_from_slice(s.as_bytes());
+            }
+
+            String::from_utf8(res).map(Cow::Owned).map_err(|_| {
+                ParseError::new(ErrorKind::InvalidUtf8, start)
+                    .with_expected(Expected::Description("UTF-8"))
+            })
+        })
+    }
+}
+
+/// Get value of a given hexadecimal digit.
+fn hex_digit(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        _ => b - b'A' + 10,
+    }
+}
+
+/// Get value of a given base64 digit (both the standard and the URL-safe
+/// alphabet are accepted).
+fn base64_digit(b: u8) -> Option<u8> {
+    let res = match b {
+        b'A'..=b'Z' => b - b'A',
+        b'a'..=b'z' => b - b'a' + 26,
+        b'0'..=b'9' => b - b'0' + 52,
+        b'+' | b'-' => 62,
+        b'/' | b'_' => 63,
+        _ => return None,
+    };
+
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use std::borrow::Cow;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_hex_bytes() {
+        let mut reader = StringReader::new("DEADbeef01x abc 0011223344");
+
+        let mut buf = [0u8; 8];
+
+        assert_eq!(reader.read_hex_bytes(&mut buf), Ok(5));
+        assert_eq!(buf[..5], [0xde, 0xad, 0xbe, 0xef, 0x01]);
+        assert_eq!(reader.read_hex_bytes(&mut buf), Ok(0));
+
+        reader.match_str("x ").unwrap();
+
+        let err = reader.read_hex_bytes(&mut buf).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 15);
+        assert_eq!(err.found(), Some('c'));
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_hex_bytes(&mut buf[..2]), Ok(2));
+        assert_eq!(buf[..2], [0x00, 0x11]);
+        assert_eq!(reader.as_str(), "223344");
+    }
+
+    #[test]
+    fn test_base64() {
+        let mut reader = StringReader::new("aGVsbG8= aGk aGk=x YQ= -_-_ YWJj YWJjZA==");
+
+        let mut buf = [0u8; 8];
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(5));
+        assert_eq!(&buf[..5], b"hello");
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(2));
+        assert_eq!(&buf[..2], b"hi");
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(2));
+        assert_eq!(reader.as_str(), "x YQ= -_-_ YWJj YWJjZA==");
+
+        reader.match_str("x ").unwrap();
+
+        let err = reader.read_base64(&mut buf).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.found(), Some('='));
+        assert_eq!(reader.as_str(), "YQ= -_-_ YWJj YWJjZA==");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(3));
+        assert_eq!(&buf[..3], [0xfb, 0xff, 0xbf]);
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf[..2]), Ok(0));
+        assert_eq!(reader.read_base64(&mut buf[..3]), Ok(3));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf[..3]), Ok(3));
+        assert_eq!(reader.as_str(), "ZA==");
+        assert_eq!(reader.read_base64(&mut buf), Ok(1));
+        assert_eq!(buf[0], b'd');
+        assert!(reader.is_empty());
+
+        assert!(StringReader::new("YWJjZ").read_base64(&mut buf).is_err());
+
+        // the alphabets cannot be mixed
+        let mut reader = StringReader::new("+/+/ -_-_ a-b_c/dd");
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(3));
+        assert_eq!(&buf[..3], [0xfb, 0xff, 0xbf]);
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_base64(&mut buf), Ok(3));
+
+        reader.skip_whitespace();
+
+        let err = reader.read_base64(&mut buf).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.found(), Some('/'));
+        assert_eq!(err.position().offset, 15);
+        assert_eq!(reader.as_str(), "a-b_c/dd");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_percent_decoded() {
+        let mut reader = StringReader::new("a=foo&b=%C4%8D%2b%2F+x&c=%zz&d=%C4&e=%4");
+
+        let is_delimiter = |c| c == '=' || c == '&';
+
+        assert_eq!(
+            reader.read_percent_decoded(is_delimiter),
+            Ok(Cow::Borrowed("a"))
+        );
+
+        reader.skip_char();
+
+        assert!(matches!(
+            reader.read_percent_decoded(is_delimiter),
+            Ok(Cow::Borrowed("foo"))
+        ));
+
+        reader.match_str("&b=").unwrap();
+
+        assert_eq!(reader.read_percent_decoded(is_delimiter).unwrap(), "č+/+x");
+
+        reader.match_str("&c=").unwrap();
+
+        let err = reader.read_percent_decoded(is_delimiter).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+        assert_eq!(err.position().column, 26);
+        assert_eq!(reader.as_str(), "%zz&d=%C4&e=%4");
+
+        reader.match_str("%zz&d=").unwrap();
+
+        let err = reader.read_percent_decoded(is_delimiter).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidUtf8);
+
+        reader.match_str("%C4&e=").unwrap();
+
+        assert!(reader.read_percent_decoded(is_delimiter).is_err());
+        assert_eq!(reader.as_str(), "%4");
+    }
+}
@@ -0,0 +1,502 @@
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    num::{IntErrorKind, ParseFloatError, ParseIntError},
+    result,
+};
+
+use crate::Position;
+
+/// Kind of a string reader error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    EmptyInput,
+    NoMatch,
+    InvalidEscape,
+    InvalidValue,
+    InvalidUtf8,
+    /// More input is needed (see `StreamingReader`).
+    Incomplete,
+    /// The input is not a valid number (see `ParseError::number_error()` and
+    /// the `From<ParseIntError>` and `From<ParseFloatError>` impls of
+    /// `ParseError`).
+    InvalidNumber,
+}
+
+impl ErrorKind {
+    /// Get a short description of the error kind.
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Self::EmptyInput => "input is empty",
+            Self::NoMatch => "the input does not match",
+            Self::InvalidEscape => "invalid escape sequence",
+            Self::InvalidValue => "invalid value",
+            Self::InvalidUtf8 => "invalid UTF-8",
+            Self::Incomplete => "incomplete input",
+            Self::InvalidNumber => "invalid number",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Kind of a floating point number error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FloatErrorKind {
+    /// The number is empty.
+    Empty,
+    /// The number is not a valid floating point literal.
+    Invalid,
+}
+
+/// Reason why a number cannot be parsed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumberError {
+    /// Invalid integer.
+    Int(IntErrorKind),
+    /// Invalid floating point number.
+    Float(FloatErrorKind),
+}
+
+impl From<&ParseIntError> for NumberError {
+    #[inline]
+    fn from(err: &ParseIntError) -> Self {
+        Self::Int(*err.kind())
+    }
+}
+
+impl From<&ParseFloatError> for NumberError {
+    fn from(err: &ParseFloatError) -> Self {
+        // The error kind is not public, so we need to compare the error with
+        // a known one.
+        let kind = if *err == "".parse::<f32>().unwrap_err() {
+            FloatErrorKind::Empty
+        } else {
+            FloatErrorKind::Invalid
+        };
+
+        Self::Float(kind)
+    }
+}
+
+/// Inline string.
+///
+/// The string is stored inline in order to avoid allocations. Strings longer
+/// than `InlineStr::CAPACITY` bytes are truncated (at a character boundary).
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InlineStr {
+    buf: [u8; InlineStr::CAPACITY],
+    len: u8,
+    truncated: bool,
+}
+
+impl InlineStr {
+    /// Maximum length of the string in bytes.
+    pub const CAPACITY: usize = 24;
+
+    /// Create a new inline string (truncating it if necessary).
+    pub(crate) fn new(s: &str) -> Self {
+        let mut len = s.len().min(Self::CAPACITY);
+
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut buf = [0u8; Self::CAPACITY];
+
+        buf[..len].copy_from_slice(&s.as_bytes()[..len]);
+
+        Self {
+            buf,
+            len: len as u8,
+            truncated: len < s.len(),
+        }
+    }
+
+    /// Get the string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // The buffer always contains a valid UTF-8 prefix.
+        core::str::from_utf8(&self.buf[..self.len as usize]).unwrap_or("")
+    }
+
+    /// Check if the original string was truncated.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl Debug for InlineStr {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for InlineStr {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        f.write_str(self.as_str())?;
+
+        if self.truncated {
+            f.write_str("...")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Expected input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Expected {
+    Char(char),
+    Str(InlineStr),
+    Byte(u8),
+    /// Any character from a given set.
+    OneOf(InlineStr),
+    /// Description of the expected input (e.g. `identifier`).
+    Description(&'static str),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        match self {
+            Self::Char(c) => write!(f, "{:?}", c),
+            Self::Str(s) => write!(f, "\"{}\"", s),
+            Self::Byte(b) => write!(f, "b'{}'", b.escape_ascii()),
+            Self::OneOf(s) => write!(f, "one of \"{}\"", s),
+            Self::Description(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Set of inputs expected at a given position.
+///
+/// The set is collected by a string reader with expectation tracking enabled
+/// (see `StringReader::with_expectation_tracking()`). Only expectations
+/// recorded at the furthest position reached are kept. Expectations beyond
+/// `ExpectedSet::CAPACITY` are dropped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExpectedSet {
+    position: Position,
+    found: Option<char>,
+    items: [Expected; ExpectedSet::CAPACITY],
+    len: u8,
+}
+
+impl ExpectedSet {
+    /// Maximum number of expectations in the set.
+    pub const CAPACITY: usize = 8;
+
+    /// Create a new empty set.
+    pub(crate) const fn new() -> Self {
+        Self {
+            position: Position::START,
+            found: None,
+            items: [Expected::Description(""); Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Record the expectation of a given error. Errors without an
+    /// expectation are ignored.
+    pub(crate) fn record(&mut self, err: &ParseError) {
+        let expected = match err.expected {
+            Some(expected) => expected,
+            None => return,
+        };
+
+        let offset = err.position.offset;
+
+        if self.is_empty() || offset > self.position.offset {
+            self.position = err.position;
+            self.found = err.found;
+            self.len = 0;
+        } else if offset < self.position.offset || self.as_slice().contains(&expected) {
+            return;
+        }
+
+        if let Some(item) = self.items.get_mut(self.len as usize) {
+            *item = expected;
+
+            self.len += 1;
+        }
+    }
+
+    /// Get the position of the expectations.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Get the character found in the input at the position. `None` means
+    /// that the end of the input was reached.
+    #[inline]
+    pub fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    /// Get the expectations.
+    #[inline]
+    pub fn as_slice(&self) -> &[Expected] {
+        &self.items[..self.len as usize]
+    }
+
+    /// Get an iterator over the expectations.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, Expected> {
+        self.as_slice().iter()
+    }
+
+    /// Get the number of expectations.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Display for ExpectedSet {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        match self.as_slice() {
+            [] => return f.write_str("nothing expected"),
+            [expected] => write!(f, "expected {}", expected)?,
+            [first, rest @ ..] => {
+                write!(f, "expected one of {}", first)?;
+
+                for expected in rest {
+                    write!(f, ", {}", expected)?;
+                }
+            }
+        }
+
+        write!(f, " at {}", self.position)?;
+
+        if let Some(c) = self.found {
+            write!(f, ", found {:?}", c)
+        } else {
+            f.write_str(", found end of input")
+        }
+    }
+}
+
+/// String reader error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ErrorKind,
+    position: Position,
+    expected: Option<Expected>,
+    found: Option<char>,
+    found_byte: Option<u8>,
+    number_error: Option<NumberError>,
+}
+
+impl ParseError {
+    /// Create a new error of a given kind at a given position.
+    pub(crate) fn new(kind: ErrorKind, position: Position) -> Self {
+        Self {
+            kind,
+            position,
+            expected: None,
+            found: None,
+            found_byte: None,
+            number_error: None,
+        }
+    }
+
+    /// Set the expected input.
+    pub(crate) fn with_expected(mut self, expected: Expected) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    /// Set the character found in the input.
+    pub(crate) fn with_found(mut self, found: Option<char>) -> Self {
+        self.found = found;
+        self
+    }
+
+    /// Set the byte found in the input.
+    pub(crate) fn with_found_byte(mut self, found: Option<u8>) -> Self {
+        self.found_byte = found;
+        self
+    }
+
+    /// Set the reason why a number cannot be parsed.
+    pub(crate) fn with_number_error(mut self, err: NumberError) -> Self {
+        self.number_error = Some(err);
+        self
+    }
+
+    /// Get the error kind.
+    #[inline]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Get the position where the error occurred.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Get the expected input (if known).
+    #[inline]
+    pub fn expected(&self) -> Option<&Expected> {
+        self.expected.as_ref()
+    }
+
+    /// Get the character found in the input at the error position. `None`
+    /// means that the end of the input was reached.
+    #[inline]
+    pub fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    /// Get the byte found in the input at the error position (for errors
+    /// reported by `BytesReader`).
+    #[inline]
+    pub fn found_byte(&self) -> Option<u8> {
+        self.found_byte
+    }
+
+    /// Get the reason why a number cannot be parsed (for errors reported by
+    /// the numeric readers, e.g. `StringReader::read_u32()`, and errors
+    /// converted from `ParseIntError` and `ParseFloatError`).
+    #[inline]
+    pub fn number_error(&self) -> Option<NumberError> {
+        self.number_error
+    }
+
+    /// Get an object that displays the error together with the offending
+    /// line of a given input and a caret pointing to the error position.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - the input the error was reported for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let input = "x = 1;\ny = 2 3;";
+    ///
+    /// let mut reader = StringReader::new(input);
+    ///
+    /// reader.read_line();
+    /// reader.match_str("y = 2").unwrap();
+    ///
+    /// let err = reader.match_char(';').unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.display_with_source(input).to_string(),
+    ///     "the input does not match at 2:6: expected ';', found ' '\n  |\n2 | y = 2 3;\n  |      ^"
+    /// );
+    /// ```
+    #[inline]
+    pub fn display_with_source<'a>(&'a self, input: &'a str) -> WithSource<'a> {
+        WithSource { error: self, input }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        write!(f, "{} at {}", self.kind, self.position)?;
+
+        if let Some(expected) = self.expected.as_ref() {
+            write!(f, ": expected {}", expected)?;
+
+            if let Some(c) = self.found {
+                write!(f, ", found {:?}", c)?;
+            } else if let Some(b) = self.found_byte {
+                write!(f, ", found b'{}'", b.escape_ascii())?;
+            } else {
+                f.write_str(", found end of input")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error displayed together with the offending line of the input.
+///
+/// The object is created by `ParseError::display_with_source()`.
+pub struct WithSource<'a> {
+    error: &'a ParseError,
+    input: &'a str,
+}
+
+impl Display for WithSource<'_> {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        let mut offset = self.error.position.offset.min(self.input.len());
+
+        while !self.input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let start = self.input[..offset]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+
+        let end = self.input[offset..]
+            .find('\n')
+            .map_or(self.input.len(), |index| offset + index);
+
+        let line = self.input[start..end].trim_end_matches('\r');
+
+        let number = self.error.position.line;
+
+        let mut width = 1;
+        let mut n = number;
+
+        while n >= 10 {
+            n /= 10;
+            width += 1;
+        }
+
+        writeln!(f, "{}", self.error)?;
+        writeln!(f, "{:width$} |", "", width = width)?;
+        writeln!(f, "{} | {}", number, line)?;
+        write!(f, "{:width$} | ", "", width = width)?;
+
+        // keep tabs so that the caret is aligned with the error position
+        for c in self.input[start..offset].chars() {
+            f.write_str(if c == '\t' { "\t" } else { " " })?;
+        }
+
+        f.write_str("^")
+    }
+}
+
+/// The error position is not known in this case, so it is set to the
+/// beginning of the input. The numeric readers (e.g.
+/// `StringReader::read_u32()`) report the actual position.
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(ErrorKind::InvalidNumber, Position::START).with_number_error((&err).into())
+    }
+}
+
+/// The error position is not known in this case, so it is set to the
+/// beginning of the input. The numeric readers (e.g.
+/// `StringReader::read_f64()`) report the actual position.
+impl From<ParseFloatError> for ParseError {
+    fn from(err: ParseFloatError) -> Self {
+        Self::new(ErrorKind::InvalidNumber, Position::START).with_number_error((&err).into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
@@ -0,0 +1,162 @@
+use core::str::FromStr;
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Padding of fixed-width fields.
+///
+/// A character can be used in place of the padding. It is equivalent to
+/// `Padding::Both`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Padding {
+    /// Padding on the left side (i.e. right-aligned content).
+    Left(char),
+    /// Padding on the right side (i.e. left-aligned content).
+    Right(char),
+    /// Padding on both sides.
+    Both(char),
+}
+
+impl Padding {
+    /// Remove the padding from a given field.
+    fn trim<'a>(&self, field: &'a str) -> &'a str {
+        match *self {
+            Self::Left(pad) => field.trim_start_matches(pad),
+            Self::Right(pad) => field.trim_end_matches(pad),
+            Self::Both(pad) => field.trim_matches(pad),
+        }
+    }
+}
+
+impl From<char> for Padding {
+    #[inline]
+    fn from(pad: char) -> Self {
+        Self::Both(pad)
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read a fixed-width field consisting of exactly `width` characters and
+    /// return its content with a given padding removed. An error is returned
+    /// if there are not enough characters left in the input. The input won't
+    /// be advanced in such case.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - width of the field in characters
+    /// * `pad` - padding of the field
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{Padding, StringReader};
+    ///
+    /// let mut reader = StringReader::new("SMITH     0000420Y");
+    ///
+    /// assert_eq!(reader.read_field(10, Padding::Right(' ')), Ok("SMITH"));
+    /// assert_eq!(reader.read_field(7, Padding::Left('0')), Ok("420"));
+    /// assert_eq!(reader.read_field(1, ' '), Ok("Y"));
+    /// assert!(reader.read_field(1, ' ').is_err());
+    /// ```
+    pub fn read_field<P>(&mut self, width: usize, pad: P) -> Result<&'a str, ParseError>
+    where
+        P: Into<Padding>,
+    {
+        let field = self.peek_str(width);
+
+        if field.chars().count() < width {
+            let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                .with_expected(Expected::Description("fixed-width field"));
+
+            return Err(err);
+        }
+
+        self.split_to(field.len());
+
+        Ok(pad.into().trim(field))
+    }
+
+    /// Read a fixed-width field consisting of exactly `width` characters and
+    /// parse its content with leading and trailing whitespace removed. An
+    /// error is returned if there are not enough characters left in the input
+    /// or if the content cannot be parsed. The input won't be advanced in
+    /// case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - width of the field in characters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("  42 1.5  x");
+    ///
+    /// assert_eq!(reader.read_fixed::<u32>(4), Ok(42));
+    /// assert_eq!(reader.read_fixed::<f64>(5), Ok(1.5));
+    /// assert!(reader.read_fixed::<u32>(2).is_err());
+    /// assert_eq!(reader.as_str(), " x");
+    /// ```
+    pub fn read_fixed<T>(&mut self, width: usize) -> Result<T, ParseError>
+    where
+        T: FromStr,
+    {
+        self.transaction(|reader| {
+            let position = reader.position();
+
+            let field = reader.read_field(width, ' ')?.trim();
+
+            field.parse().map_err(|_| {
+                ParseError::new(ErrorKind::InvalidValue, position)
+                    .with_expected(Expected::Description("fixed-width value"))
+                    .with_found(field.chars().next())
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, Padding, StringReader};
+
+    #[test]
+    fn test_read_field() {
+        let mut reader = StringReader::new("**žluť**  007x\n");
+
+        assert_eq!(reader.read_field(8, '*'), Ok("žluť"));
+        assert_eq!(reader.read_field(5, Padding::Left(' ')), Ok("007"));
+        assert_eq!(reader.read_field(0, ' '), Ok(""));
+
+        let err = reader.read_field(3, Padding::Right(' ')).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().line, 2);
+        assert_eq!(reader.as_str(), "x\n");
+
+        assert_eq!(reader.read_field(2, Padding::Right('\n')), Ok("x"));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_fixed() {
+        let mut reader = StringReader::new("\t-12 2x 33");
+
+        assert_eq!(reader.read_fixed::<i32>(5), Ok(-12));
+
+        let err = reader.read_fixed::<u8>(2).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.found(), Some('2'));
+        assert_eq!(err.position().column, 6);
+        assert_eq!(reader.as_str(), "2x 33");
+
+        assert_eq!(reader.read_fixed::<char>(1), Ok('2'));
+        assert_eq!(reader.read_fixed::<char>(1), Ok('x'));
+
+        let err = reader.read_fixed::<u8>(4).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(reader.read_fixed::<u8>(3), Ok(33));
+        assert!(reader.is_empty());
+    }
+}
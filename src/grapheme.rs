@@ -0,0 +1,43 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Get the current extended grapheme cluster (if any) without advancing
+    /// the input.
+    #[inline]
+    pub fn current_grapheme(&self) -> Option<&'a str> {
+        self.input.as_str().graphemes(true).next()
+    }
+
+    /// Read the next extended grapheme cluster (e.g. an emoji sequence or a
+    /// character followed by combining marks).
+    pub fn read_grapheme(&mut self) -> Result<&'a str, ParseError> {
+        let grapheme = self.current_grapheme().ok_or_else(|| {
+            self.error(ErrorKind::EmptyInput)
+                .with_expected(Expected::Description("grapheme"))
+        })?;
+
+        Ok(self.split_to(grapheme.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_grapheme() {
+        let mut reader = StringReader::new("e\u{301}👨‍👩‍👧!");
+
+        assert_eq!(reader.current_grapheme(), Some("e\u{301}"));
+        assert_eq!(reader.read_grapheme(), Ok("e\u{301}"));
+        assert_eq!(reader.read_grapheme(), Ok("👨‍👩‍👧"));
+        assert_eq!(reader.read_char(), Ok('!'));
+        assert_eq!(reader.current_grapheme(), None);
+        assert_eq!(
+            reader.read_grapheme().unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+    }
+}
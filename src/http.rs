@@ -0,0 +1,705 @@
+//! Helpers for parsing structured HTTP header values (RFC 9110).
+//!
+//! The module provides routines for reading tokens, quoted strings, quality
+//! values, parameters and comma-separated lists. None of them allocates;
+//! quoted strings are returned as slices of the input and unescaped lazily.
+//!
+//! # Example
+//!
+//! ```
+//! use str_reader::{http, StringReader};
+//!
+//! let mut reader = StringReader::new("text/html, application/json;q=0.9, */*;q=0.1");
+//!
+//! let accept = http::list(&mut reader, |r| r.read_mime_type())
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(accept.len(), 3);
+//! assert_eq!(accept[1].subtype(), "json");
+//! assert_eq!(accept[1].params().quality(), Ok(900));
+//!
+//! let mut reader = StringReader::new("no-cache, max-age=60, private=\"x-foo\"");
+//!
+//! let directives = http::list(&mut reader, http::read_directive)
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(directives[0], ("no-cache", None));
+//! assert_eq!(directives[1].1.unwrap(), "60");
+//! assert_eq!(directives[2].1.unwrap(), "x-foo");
+//! ```
+
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+use crate::{ErrorKind, Expected, ParseError, Position, StringReader};
+
+/// Token or quoted string.
+///
+/// Quoted strings are kept in their escaped form (without the surrounding
+/// quotes) and unescaped on demand.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Value<'a> {
+    Token(&'a str),
+    Quoted(&'a str),
+}
+
+impl<'a> Value<'a> {
+    /// Get the value as it appears in the input (without the surrounding
+    /// quotes in case of a quoted string).
+    #[inline]
+    pub fn raw(&self) -> &'a str {
+        match *self {
+            Self::Token(s) => s,
+            Self::Quoted(s) => s,
+        }
+    }
+
+    /// Get an iterator over the unescaped characters.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        let quoted = matches!(self, Self::Quoted(_));
+
+        let mut chars = self.raw().chars();
+
+        core::iter::from_fn(move || match chars.next()? {
+            '\\' if quoted => chars.next(),
+            c => Some(c),
+        })
+    }
+
+    /// Get the unescaped value. The returned string borrows the input if
+    /// the value does not contain any escape sequences.
+    #[cfg(feature = "alloc")]
+    pub fn to_str(&self) -> Cow<'a, str> {
+        match *self {
+            Self::Quoted(s) if s.contains('\\') => Cow::Owned(self.chars().collect()),
+            _ => Cow::Borrowed(self.raw()),
+        }
+    }
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        self.chars().try_for_each(|c| fmt::Write::write_char(f, c))
+    }
+}
+
+impl PartialEq<str> for Value<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for Value<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Parameters (i.e. `;name=value` pairs) of a header value.
+///
+/// The parameters can be read using `read_params()`. The parameters are
+/// validated when read and decoded lazily.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Params<'a> {
+    input: &'a str,
+    position: Position,
+}
+
+impl<'a> Params<'a> {
+    /// Get an iterator over the parameters.
+    #[inline]
+    pub fn iter(&self) -> ParamsIter<'a> {
+        ParamsIter {
+            reader: StringReader::new(self.input).with_position(self.position),
+        }
+    }
+
+    /// Get value of a given parameter. Parameter names are
+    /// case-insensitive.
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        self.iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Get the quality value of the `q` parameter in thousandths (see
+    /// `read_quality()`). The quality value defaults to 1000 if there is no
+    /// such parameter.
+    pub fn quality(&self) -> Result<u16, ParseError> {
+        let value = match self.get("q") {
+            Some(Value::Token(value)) => value,
+            Some(Value::Quoted(value)) => {
+                let err = ParseError::new(ErrorKind::InvalidValue, self.position_of(value))
+                    .with_expected(Expected::Description("quality value"))
+                    .with_found(Some('"'));
+
+                return Err(err);
+            }
+            None => return Ok(1000),
+        };
+
+        let mut reader = StringReader::new(value).with_position(self.position_of(value));
+
+        let res = read_quality(&mut reader)?;
+
+        if let Some(c) = reader.current_char() {
+            let err = reader
+                .error(ErrorKind::InvalidValue)
+                .with_expected(Expected::Description("quality value"))
+                .with_found(Some(c));
+
+            return Err(err);
+        }
+
+        Ok(res)
+    }
+
+    /// Get position of a given slice of the parameters.
+    fn position_of(&self, value: &str) -> Position {
+        let offset = value.as_ptr() as usize - self.input.as_ptr() as usize;
+
+        let mut position = self.position;
+
+        position.advance_str(&self.input[..offset]);
+
+        position
+    }
+}
+
+impl<'a> IntoIterator for Params<'a> {
+    type Item = (&'a str, Value<'a>);
+    type IntoIter = ParamsIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over parameters.
+///
+/// The iterator is created by `Params::iter()`.
+pub struct ParamsIter<'a> {
+    reader: StringReader<'a>,
+}
+
+impl<'a> Iterator for ParamsIter<'a> {
+    type Item = (&'a str, Value<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the parameters have been validated already, so the only errors
+        // here are the empty parameters
+        loop {
+            skip_whitespace(&mut self.reader);
+
+            self.reader.match_char(';').ok()?;
+
+            skip_whitespace(&mut self.reader);
+
+            if let Ok(param) = read_param(&mut self.reader) {
+                return Some(param);
+            }
+        }
+    }
+}
+
+impl FusedIterator for ParamsIter<'_> {}
+
+/// A value followed by parameters (e.g. `attachment; filename="a.txt"`).
+///
+/// The parameterized value can be read using `read_parameterized()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Parameterized<'a, T> {
+    pub value: T,
+    pub params: Params<'a>,
+}
+
+/// Iterator over elements of a comma-separated list.
+///
+/// The iterator is created by `list()`.
+pub struct List<'r, 'a, F> {
+    reader: &'r mut StringReader<'a>,
+    parse: F,
+    first: bool,
+    done: bool,
+}
+
+impl<'a, F, T> Iterator for List<'_, 'a, F>
+where
+    F: FnMut(&mut StringReader<'a>) -> Result<T, ParseError>,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let first = self.first;
+
+        self.first = false;
+
+        let res = self.reader.transaction(|reader| {
+            skip_whitespace(reader);
+
+            if !first && !reader.is_empty() {
+                reader.match_char(',')?;
+            }
+
+            // empty list elements are allowed
+            loop {
+                skip_whitespace(reader);
+
+                if reader.match_char(',').is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        });
+
+        if let Err(err) = res {
+            self.done = true;
+
+            return Some(Err(err));
+        }
+
+        if self.reader.is_empty() {
+            self.done = true;
+
+            return None;
+        }
+
+        let parse = &mut self.parse;
+
+        let res = self.reader.transaction(|reader| parse(reader));
+
+        self.done = res.is_err();
+
+        Some(res)
+    }
+}
+
+impl<'a, F, T> FusedIterator for List<'_, 'a, F> where
+    F: FnMut(&mut StringReader<'a>) -> Result<T, ParseError>
+{
+}
+
+/// Get an iterator over elements of a comma-separated list spanning the
+/// rest of the input.
+///
+/// Each element is read using a given parsing function. Whitespace around
+/// the commas and empty elements are skipped. The iterator stops after the
+/// first error. The reader will be positioned at the beginning of the
+/// element (or the missing comma) in such case.
+///
+/// # Arguments
+///
+/// * `reader` - string reader
+/// * `parse` - parsing function for the list elements
+#[inline]
+pub fn list<'r, 'a, F, T>(reader: &'r mut StringReader<'a>, parse: F) -> List<'r, 'a, F>
+where
+    F: FnMut(&mut StringReader<'a>) -> Result<T, ParseError>,
+{
+    List {
+        reader,
+        parse,
+        first: true,
+        done: false,
+    }
+}
+
+/// Skip optional whitespace (spaces and tabs).
+#[inline]
+pub fn skip_whitespace(reader: &mut StringReader) {
+    reader.skip_while(|c| c == ' ' || c == '\t');
+}
+
+/// Read a token (i.e. a non-empty sequence of alphanumeric characters and
+/// ``!#$%&'*+-.^_`|~``).
+pub fn read_token<'a>(reader: &mut StringReader<'a>) -> Result<&'a str, ParseError> {
+    let token = reader.read_while(is_tchar);
+
+    if token.is_empty() {
+        let kind = if reader.is_empty() {
+            ErrorKind::EmptyInput
+        } else {
+            ErrorKind::NoMatch
+        };
+
+        let err = reader
+            .error(kind)
+            .with_expected(Expected::Description("token"))
+            .with_found(reader.current_char());
+
+        return Err(err);
+    }
+
+    Ok(token)
+}
+
+/// Read a quoted string. Any visible character, space or tab can be escaped
+/// using a backslash. The input won't be advanced in case of an error.
+pub fn read_quoted_string<'a>(reader: &mut StringReader<'a>) -> Result<Value<'a>, ParseError> {
+    reader.transaction(|reader| {
+        reader.match_char('"')?;
+
+        let input = reader.as_str();
+
+        loop {
+            reader.skip_while(is_qdtext);
+
+            match reader.current_char() {
+                Some('"') => break,
+                Some('\\') => {
+                    reader.skip_char();
+
+                    match reader.current_char() {
+                        Some(c) if c == '"' || c == '\\' || is_qdtext(c) => reader.skip_char(),
+                        c => {
+                            let err = reader.error(ErrorKind::InvalidEscape).with_found(c);
+
+                            return Err(err);
+                        }
+                    }
+                }
+                c => {
+                    let kind = if c.is_some() {
+                        ErrorKind::NoMatch
+                    } else {
+                        ErrorKind::EmptyInput
+                    };
+
+                    let err = reader
+                        .error(kind)
+                        .with_expected(Expected::Char('"'))
+                        .with_found(c);
+
+                    return Err(err);
+                }
+            }
+        }
+
+        let value = &input[..input.len() - reader.as_str().len()];
+
+        reader.skip_char();
+
+        Ok(Value::Quoted(value))
+    })
+}
+
+/// Read a token or a quoted string.
+pub fn read_value<'a>(reader: &mut StringReader<'a>) -> Result<Value<'a>, ParseError> {
+    if reader.current_char() == Some('"') {
+        read_quoted_string(reader)
+    } else {
+        read_token(reader).map(Value::Token)
+    }
+}
+
+/// Read a quality value (e.g. `0.8`) and return it in thousandths (i.e. in
+/// the range from 0 to 1000). The input won't be advanced in case of an
+/// error.
+pub fn read_quality(reader: &mut StringReader) -> Result<u16, ParseError> {
+    reader.transaction(|reader| {
+        let start = reader.position();
+
+        let mut res = match reader.current_char() {
+            Some('0') => 0,
+            Some('1') => 1000,
+            c => {
+                let kind = if c.is_some() {
+                    ErrorKind::NoMatch
+                } else {
+                    ErrorKind::EmptyInput
+                };
+
+                let err = reader
+                    .error(kind)
+                    .with_expected(Expected::Description("quality value"))
+                    .with_found(c);
+
+                return Err(err);
+            }
+        };
+
+        reader.skip_char();
+
+        if reader.match_char('.').is_ok() {
+            let digits = reader
+                .as_str()
+                .bytes()
+                .take(3)
+                .take_while(u8::is_ascii_digit)
+                .count();
+
+            let digits = reader.split_to(digits);
+
+            for (b, scale) in digits.bytes().zip([100, 10, 1]) {
+                res += u16::from(b - b'0') * scale;
+            }
+        }
+
+        if res > 1000 {
+            let err = ParseError::new(ErrorKind::InvalidValue, start)
+                .with_expected(Expected::Description("quality value"))
+                .with_found(Some('1'));
+
+            return Err(err);
+        }
+
+        Ok(res)
+    })
+}
+
+/// Read parameters (i.e. `;name=value` pairs, see `Params`) starting at the
+/// current position. Parameter names are tokens and parameter values are
+/// tokens or quoted strings. Whitespace around the semicolons and empty
+/// parameters are allowed. The method stops after the last parameter. The
+/// input won't be advanced in case of an error.
+pub fn read_params<'a>(reader: &mut StringReader<'a>) -> Result<Params<'a>, ParseError> {
+    reader.transaction(|reader| {
+        let input = reader.as_str();
+        let position = reader.position();
+
+        loop {
+            let checkpoint = reader.checkpoint();
+
+            skip_whitespace(reader);
+
+            if reader.match_char(';').is_err() {
+                reader.rewind(checkpoint);
+                break;
+            }
+
+            skip_whitespace(reader);
+
+            match reader.current_char() {
+                Some(';') | Some(',') | None => (),
+                _ => {
+                    read_param(reader)?;
+                }
+            }
+        }
+
+        let input = &input[..input.len() - reader.as_str().len()];
+
+        Ok(Params { input, position })
+    })
+}
+
+/// Read a value using a given parsing function followed by parameters (see
+/// `read_params()`). The input won't be advanced in case of an error.
+///
+/// # Arguments
+///
+/// * `reader` - string reader
+/// * `parse` - parsing function for the value
+pub fn read_parameterized<'a, F, T>(
+    reader: &mut StringReader<'a>,
+    parse: F,
+) -> Result<Parameterized<'a, T>, ParseError>
+where
+    F: FnOnce(&mut StringReader<'a>) -> Result<T, ParseError>,
+{
+    reader.transaction(|reader| {
+        let value = parse(reader)?;
+        let params = read_params(reader)?;
+
+        Ok(Parameterized { value, params })
+    })
+}
+
+/// Read a directive (i.e. a token optionally followed by `=` and a value,
+/// e.g. `max-age=60`). The input won't be advanced in case of an error.
+pub fn read_directive<'a>(
+    reader: &mut StringReader<'a>,
+) -> Result<(&'a str, Option<Value<'a>>), ParseError> {
+    reader.transaction(|reader| {
+        let name = read_token(reader)?;
+
+        let value = if reader.match_char('=').is_ok() {
+            Some(read_value(reader)?)
+        } else {
+            None
+        };
+
+        Ok((name, value))
+    })
+}
+
+/// Read a single parameter.
+fn read_param<'a>(reader: &mut StringReader<'a>) -> Result<(&'a str, Value<'a>), ParseError> {
+    let name = read_token(reader)?;
+
+    reader.match_char('=')?;
+
+    let value = read_value(reader)?;
+
+    Ok((name, value))
+}
+
+/// Check if a given character can be part of a token.
+fn is_tchar(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+}
+
+/// Check if a given character can appear unescaped in a quoted string.
+/// Non-ASCII characters are allowed as `obs-text`.
+fn is_qdtext(c: char) -> bool {
+    match c {
+        '\t' | ' ' => true,
+        '"' | '\\' => false,
+        c => c.is_ascii_graphic() || !c.is_ascii(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    use crate::{http, ErrorKind, StringReader};
+
+    #[test]
+    fn test_value() {
+        let mut reader = StringReader::new("\"a \\\"b\\\\\" c \"\\\u{7f}\" \"x");
+
+        let value = http::read_value(&mut reader).unwrap();
+
+        assert_eq!(value, Value::Quoted("a \\\"b\\\\"));
+        assert_eq!(value, "a \"b\\");
+        assert_eq!(value.to_string(), "a \"b\\");
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(value.to_str(), "a \"b\\");
+
+        http::skip_whitespace(&mut reader);
+
+        assert_eq!(http::read_value(&mut reader), Ok(Value::Token("c")));
+
+        http::skip_whitespace(&mut reader);
+
+        let err = http::read_value(&mut reader).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+        assert_eq!(reader.as_str(), "\"\\\u{7f}\" \"x");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = http::read_quoted_string(&mut reader).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+
+        assert!(http::read_token(&mut StringReader::new("(x)")).is_err());
+    }
+
+    #[test]
+    fn test_quality() {
+        let mut reader = StringReader::new("1 0.5 1.000 0. 0.1234 1.001 2");
+
+        let mut next = || {
+            let res = http::read_quality(&mut reader);
+
+            reader.skip_whitespace();
+
+            res
+        };
+
+        assert_eq!(next(), Ok(1000));
+        assert_eq!(next(), Ok(500));
+        assert_eq!(next(), Ok(1000));
+        assert_eq!(next(), Ok(0));
+        assert_eq!(next(), Ok(123));
+        assert_eq!(reader.as_str(), "4 1.001 2");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(
+            http::read_quality(&mut reader).unwrap_err().kind(),
+            ErrorKind::InvalidValue
+        );
+        assert_eq!(reader.as_str(), "1.001 2");
+    }
+
+    #[test]
+    fn test_params() {
+        let mut reader =
+            StringReader::new("attachment ; filename=\"a b.txt\";; Q=0.5 , x; q=\"1\", y; q=0.5x");
+
+        let item = http::read_parameterized(&mut reader, http::read_token).unwrap();
+
+        assert_eq!(item.value, "attachment");
+        assert_eq!(item.params.iter().count(), 2);
+        assert_eq!(item.params.get("FileName").unwrap(), "a b.txt");
+        assert_eq!(item.params.get("name"), None);
+        assert_eq!(item.params.quality(), Ok(500));
+        assert_eq!(reader.as_str(), " , x; q=\"1\", y; q=0.5x");
+
+        reader.match_str(" , ").unwrap();
+
+        let item = http::read_parameterized(&mut reader, http::read_token).unwrap();
+
+        let err = item.params.quality().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 49);
+
+        reader.match_str(", ").unwrap();
+
+        let item = http::read_parameterized(&mut reader, http::read_token).unwrap();
+
+        let err = item.params.quality().unwrap_err();
+
+        assert_eq!(err.found(), Some('x'));
+        assert_eq!(err.position().column, 61);
+
+        let params = http::read_params(&mut StringReader::new("x")).unwrap();
+
+        assert_eq!(params.iter().count(), 0);
+        assert_eq!(params.quality(), Ok(1000));
+
+        let mut reader = StringReader::new("; a=b; c");
+
+        assert!(http::read_params(&mut reader).is_err());
+        assert_eq!(reader.as_str(), "; a=b; c");
+    }
+
+    #[test]
+    fn test_list() {
+        let mut reader = StringReader::new(" , a,b=1 ,, c=\"x y\" ,  ");
+
+        let items = http::list(&mut reader, http::read_directive)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            items,
+            [
+                ("a", None),
+                ("b", Some(Value::Token("1"))),
+                ("c", Some(Value::Quoted("x y"))),
+            ]
+        );
+        assert!(reader.is_empty());
+
+        let mut reader = StringReader::new("a b");
+
+        let mut items = http::list(&mut reader, http::read_token);
+
+        assert_eq!(items.next(), Some(Ok("a")));
+        assert!(matches!(items.next(), Some(Err(_))));
+        assert_eq!(items.next(), None);
+        assert_eq!(reader.as_str(), " b");
+    }
+}
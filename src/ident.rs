@@ -0,0 +1,329 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Set of keywords.
+///
+/// The set is meant to be built once and used for matching keywords using
+/// `StringReader::match_keyword_set()`. Lookups take logarithmic time with
+/// respect to the number of keywords. Keywords are expected to consist of
+/// identifier characters (i.e. alphanumeric characters and underscores).
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{KeywordSet, StringReader};
+///
+/// let keywords = KeywordSet::new(["let", "if", "else"]);
+///
+/// let mut reader = StringReader::new("else iffy");
+///
+/// assert_eq!(reader.match_keyword_set(&keywords), Some(2));
+///
+/// reader.skip_whitespace();
+///
+/// assert_eq!(reader.match_keyword_set(&keywords), None);
+/// assert_eq!(reader.as_str(), "iffy");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct KeywordSet<'k> {
+    sorted: Vec<(&'k str, usize)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'k> KeywordSet<'k> {
+    /// Create a new keyword set. Keywords are identified by their index in
+    /// the given sequence. If a keyword is present multiple times, its first
+    /// index is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `keywords` - the keywords
+    pub fn new<I>(keywords: I) -> Self
+    where
+        I: IntoIterator<Item = &'k str>,
+    {
+        let mut sorted = keywords
+            .into_iter()
+            .enumerate()
+            .map(|(index, keyword)| (keyword, index))
+            .collect::<Vec<_>>();
+
+        // the sort is stable, so the first index is kept
+        sorted.sort_by_key(|&(keyword, _)| keyword);
+        sorted.dedup_by_key(|&mut (keyword, _)| keyword);
+
+        Self { sorted }
+    }
+
+    /// Get the index of a given keyword.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - the keyword
+    pub fn get(&self, word: &str) -> Option<usize> {
+        self.sorted
+            .binary_search_by_key(&word, |&(keyword, _)| keyword)
+            .ok()
+            .map(|index| self.sorted[index].1)
+    }
+
+    /// Get the number of distinct keywords.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read an ASCII identifier starting at the current position. The
+    /// identifier must start with an ASCII letter or an underscore and it may
+    /// contain ASCII letters, digits and underscores.
+    #[inline]
+    pub fn read_identifier(&mut self) -> Result<&'a str, ParseError> {
+        self.read_identifier_by(
+            |c| c.is_ascii_alphabetic() || c == '_',
+            |c| c.is_ascii_alphanumeric() || c == '_',
+        )
+    }
+
+    /// Read a Unicode identifier starting at the current position. The
+    /// identifier must start with a character having the `XID_Start` property
+    /// or an underscore and it may contain characters having the
+    /// `XID_Continue` property.
+    #[cfg(feature = "unicode-xid")]
+    #[inline]
+    pub fn read_unicode_identifier(&mut self) -> Result<&'a str, ParseError> {
+        use unicode_xid::UnicodeXID;
+
+        self.read_identifier_by(
+            |c| UnicodeXID::is_xid_start(c) || c == '_',
+            UnicodeXID::is_xid_continue,
+        )
+    }
+
+    /// Match a given keyword to the input and, if successful, advance the
+    /// input by the length of the keyword. Unlike `match_str()`, the keyword
+    /// must be followed by a character that cannot be part of an identifier
+    /// (i.e. anything except alphanumeric characters and underscores) or by
+    /// the end of the input. The input won't be advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyword` - expected keyword
+    pub fn match_keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        let start = self.checkpoint();
+
+        self.match_str(keyword)?;
+
+        match self.current_char() {
+            Some(c) if is_identifier_char(c) => {
+                let err = self
+                    .error(ErrorKind::NoMatch)
+                    .with_expected(Expected::Description("end of keyword"))
+                    .with_found(Some(c));
+
+                self.rewind(start);
+
+                Err(err)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Match a keyword from a given set and, if successful, advance the
+    /// input past the keyword and return its index (see `KeywordSet::new()`).
+    /// The keyword must be followed by a character that cannot be part of an
+    /// identifier or by the end of the input (see `match_keyword()`). The
+    /// input won't be advanced if no keyword matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - the keywords
+    #[cfg(feature = "alloc")]
+    pub fn match_keyword_set(&mut self, set: &KeywordSet) -> Option<usize> {
+        let rest = self.input.as_str();
+
+        let end = rest.find(|c| !is_identifier_char(c)).unwrap_or(rest.len());
+
+        let index = set.get(&rest[..end])?;
+
+        self.split_to(end);
+
+        Some(index)
+    }
+
+    /// Read an identifier using given character classes.
+    fn read_identifier_by<S, C>(
+        &mut self,
+        is_start: S,
+        mut is_continue: C,
+    ) -> Result<&'a str, ParseError>
+    where
+        S: FnOnce(char) -> bool,
+        C: FnMut(char) -> bool,
+    {
+        match self.current_char() {
+            Some(c) if is_start(c) => (),
+            c => {
+                let kind = if c.is_some() {
+                    ErrorKind::NoMatch
+                } else {
+                    ErrorKind::EmptyInput
+                };
+
+                let err = self
+                    .error(kind)
+                    .with_expected(Expected::Description("identifier"))
+                    .with_found(c);
+
+                return Err(err);
+            }
+        }
+
+        let rest = self.input.as_str();
+
+        let index = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| !is_continue(c))
+            .map(|(index, _)| index)
+            .unwrap_or(rest.len());
+
+        Ok(self.split_to(index))
+    }
+}
+
+/// Check if a given character can be part of an identifier.
+pub(crate) fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StringReader;
+
+    #[test]
+    fn test_identifier() {
+        let mut reader = StringReader::new("_foo1+bar 1x čaj");
+
+        assert_eq!(reader.read_identifier(), Ok("_foo1"));
+        assert!(reader.read_identifier().is_err());
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_identifier(), Ok("bar"));
+
+        reader.skip_whitespace();
+
+        let err = reader.read_identifier().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "the input does not match at 1:11: expected identifier, found '1'"
+        );
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.read_identifier().is_err());
+    }
+
+    #[test]
+    fn test_keyword() {
+        let mut reader = StringReader::new("ifdef if(x) if_ ifč if");
+
+        let err = reader.match_keyword("if").unwrap_err();
+
+        assert_eq!(err.position().offset, 2);
+        assert_eq!(err.found(), Some('d'));
+
+        assert!(reader.match_keyword("ifdef").is_ok());
+
+        reader.skip_whitespace();
+
+        assert!(reader.match_keyword("if").is_ok());
+        assert_eq!(reader.as_str(), "(x) if_ ifč if");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.match_keyword("if").is_err());
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.match_keyword("if").is_err());
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.match_keyword("if").is_ok());
+        assert!(reader.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_keyword_set() {
+        use crate::KeywordSet;
+
+        let keywords = KeywordSet::new(["for", "fn", "if", "for", "in", "ifč"]);
+
+        assert_eq!(keywords.len(), 5);
+        assert_eq!(keywords.get("for"), Some(0));
+
+        let mut reader = StringReader::new("fn(x) for_ fork ifč in");
+
+        assert_eq!(reader.match_keyword_set(&keywords), Some(1));
+        assert_eq!(reader.as_str(), "(x) for_ fork ifč in");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.match_keyword_set(&keywords), None);
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.match_keyword_set(&keywords), None);
+        assert_eq!(reader.as_str(), "fork ifč in");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.match_keyword_set(&keywords), Some(5));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.match_keyword_set(&keywords), Some(4));
+        assert!(reader.is_empty());
+    }
+
+    #[cfg(feature = "unicode-xid")]
+    #[test]
+    fn test_unicode_identifier() {
+        let mut reader = StringReader::new("čaj_2ž-x Δx 1");
+
+        assert_eq!(reader.read_unicode_identifier(), Ok("čaj_2ž"));
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_unicode_identifier(), Ok("x"));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_unicode_identifier(), Ok("Δx"));
+
+        reader.skip_whitespace();
+
+        assert!(reader.read_unicode_identifier().is_err());
+    }
+}
@@ -0,0 +1,351 @@
+use core::{iter::FusedIterator, marker::PhantomData, str::FromStr};
+
+use crate::StringReader;
+
+impl<'a> StringReader<'a> {
+    /// Get an iterator over the remaining characters. The iterator advances
+    /// the reader as it reads the characters, so iterator adaptors can be
+    /// combined with other methods of the reader. Note that some adaptors
+    /// (e.g. `take_while()`) consume one more character than they yield.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("abc=def");
+    ///
+    /// let index = reader.chars_by_ref().position(|c| c == '=');
+    ///
+    /// assert_eq!(index, Some(3));
+    /// assert_eq!(reader.read_word(), "def");
+    /// ```
+    #[inline]
+    pub fn chars_by_ref(&mut self) -> CharsByRef<'_, 'a> {
+        CharsByRef { reader: self }
+    }
+
+    /// Get an iterator over the remaining words. The iterator advances the
+    /// reader as it reads the words (see `read_word()`).
+    #[inline]
+    pub fn words(&mut self) -> Words<'_, 'a> {
+        Words { reader: self }
+    }
+
+    /// Get an iterator over the remaining lines. Each line is represented by a
+    /// separate reader (inheriting settings of this reader). Positions
+    /// reported by the line readers are relative to the input of this reader.
+    /// Line terminators are not included in the lines (see `read_line()`).
+    #[inline]
+    pub fn lines(&mut self) -> Lines<'_, 'a> {
+        Lines { reader: self }
+    }
+
+    /// Get an iterator parsing a list of values separated by a given
+    /// delimiter. The values may be surrounded by whitespace. The list ends
+    /// at the end of the input or at a closing character (see
+    /// `Delimited::until()`). The closing character is not consumed.
+    ///
+    /// The iterator stops after the first value that cannot be parsed. The
+    /// reader will be positioned at the beginning of the value in such case.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - value delimiter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("[1, 2 ,3] rest");
+    ///
+    /// reader.match_char('[').unwrap();
+    ///
+    /// let values = reader
+    ///     .parse_delimited::<u32>(',')
+    ///     .until(']')
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(values, [1, 2, 3]);
+    /// assert_eq!(reader.as_str(), "] rest");
+    /// ```
+    #[inline]
+    pub fn parse_delimited<T>(&mut self, delimiter: char) -> Delimited<'_, 'a, T> {
+        Delimited {
+            reader: self,
+            delimiter,
+            close: None,
+            first: true,
+            done: false,
+            _type: PhantomData,
+        }
+    }
+}
+
+/// Iterator over characters.
+///
+/// The iterator is created by `StringReader::chars_by_ref()`.
+pub struct CharsByRef<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+}
+
+impl Iterator for CharsByRef<'_, '_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_char().ok()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.reader.as_str().len();
+
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl FusedIterator for CharsByRef<'_, '_> {}
+
+/// Iterator over words.
+///
+/// The iterator is created by `StringReader::words()`.
+pub struct Words<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+}
+
+impl<'a> Iterator for Words<'_, 'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let word = self.reader.read_word();
+
+        if word.is_empty() {
+            None
+        } else {
+            Some(word)
+        }
+    }
+}
+
+impl FusedIterator for Words<'_, '_> {}
+
+/// Iterator over lines.
+///
+/// The iterator is created by `StringReader::lines()`.
+pub struct Lines<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+}
+
+impl<'a> Iterator for Lines<'_, 'a> {
+    type Item = StringReader<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.is_empty() {
+            return None;
+        }
+
+        let position = self.reader.position();
+
+        let line = self.reader.read_line();
+
+        Some(self.reader.sub_reader(line, position))
+    }
+}
+
+impl FusedIterator for Lines<'_, '_> {}
+
+/// Iterator parsing delimited values.
+///
+/// The iterator is created by `StringReader::parse_delimited()`.
+pub struct Delimited<'r, 'a, T> {
+    reader: &'r mut StringReader<'a>,
+    delimiter: char,
+    close: Option<char>,
+    first: bool,
+    done: bool,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Delimited<'_, '_, T> {
+    /// Stop at a given closing character.
+    #[inline]
+    pub fn until(mut self, close: char) -> Self {
+        self.close = Some(close);
+        self
+    }
+}
+
+impl<T> Iterator for Delimited<'_, '_, T>
+where
+    T: FromStr,
+{
+    type Item = Result<T, T::Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.reader.checkpoint();
+
+        self.reader.skip_while(char::is_whitespace);
+
+        let current = self.reader.current_char();
+
+        if self.first && (current.is_none() || current == self.close) {
+            self.done = true;
+
+            return None;
+        }
+
+        self.first = false;
+
+        let delimiter = self.delimiter;
+        let close = self.close;
+
+        let value = self
+            .reader
+            .read_until(|c| c == delimiter || Some(c) == close)
+            .trim_end();
+
+        match value.parse() {
+            Ok(value) => {
+                if self.reader.match_char(delimiter).is_err() {
+                    self.done = true;
+                }
+
+                Some(Ok(value))
+            }
+            Err(err) => {
+                self.reader.rewind(start);
+                self.reader.skip_while(char::is_whitespace);
+
+                self.done = true;
+
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Delimited<'_, '_, T> where T: FromStr {}
+
+#[cfg(test)]
+mod tests {
+    use crate::StringReader;
+
+    #[test]
+    fn test_chars_by_ref() {
+        let mut reader = StringReader::new("ab\ncd 12");
+
+        let letters = reader.chars_by_ref().take(2).collect::<String>();
+
+        assert_eq!(letters, "ab");
+        assert_eq!(reader.position().column, 3);
+
+        reader.match_char('\n').unwrap();
+
+        assert_eq!(reader.chars_by_ref().enumerate().nth(2), Some((2, ' ')));
+        assert_eq!(reader.position().line, 2);
+        assert_eq!(reader.chars_by_ref().size_hint(), (1, Some(2)));
+        assert_eq!(reader.read_u32(), Ok(12));
+        assert_eq!(reader.chars_by_ref().next(), None);
+    }
+
+    #[test]
+    fn test_words() {
+        let mut reader = StringReader::new("  1 2\t3\n 4  ");
+
+        let numbers = reader
+            .words()
+            .map(|w| w.parse::<u32>().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, [1, 2, 3, 4]);
+        assert!(reader.is_empty());
+
+        let mut reader = StringReader::new("a b c");
+
+        assert_eq!(reader.words().next(), Some("a"));
+        assert_eq!(reader.as_str(), " b c");
+        assert_eq!(reader.words().count(), 2);
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut reader = StringReader::new("[section]\r\nkey = 10\n\nfoo bar\n");
+
+        let mut lines = reader.lines();
+
+        let mut line = lines.next().unwrap();
+
+        assert_eq!(line.as_str(), "[section]");
+
+        let mut line2 = lines.next().unwrap();
+
+        assert_eq!(line2.read_word(), "key");
+        assert_eq!(line2.read_word(), "=");
+        assert_eq!(line2.read_u32(), Ok(10));
+        assert!(line2.is_empty());
+
+        assert!(lines.next().unwrap().is_empty());
+
+        let mut line4 = lines.next().unwrap();
+
+        line4.read_word();
+        line4.skip_whitespace();
+
+        let err = line4.match_str("baz").unwrap_err();
+
+        assert_eq!(err.position().line, 4);
+        assert_eq!(err.position().column, 7);
+
+        assert!(lines.next().is_none());
+
+        line.match_char('[').unwrap();
+
+        assert_eq!(line.position().offset, 1);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_delimited() {
+        let mut reader = StringReader::new("1, 2, 3, 4");
+
+        let values = reader
+            .parse_delimited::<u32>(',')
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(values, [1, 2, 3, 4]);
+        assert!(reader.is_empty());
+
+        let mut reader = StringReader::new("( ) (1; x; 3)");
+
+        reader.match_char('(').unwrap();
+
+        assert_eq!(reader.parse_delimited::<u32>(';').until(')').count(), 0);
+        assert_eq!(reader.as_str(), ") (1; x; 3)");
+
+        reader.match_str(") (").unwrap();
+
+        let mut values = reader.parse_delimited::<u32>(';').until(')');
+
+        assert_eq!(values.next(), Some(Ok(1)));
+        assert!(values.next().unwrap().is_err());
+        assert!(values.next().is_none());
+        assert_eq!(reader.as_str(), "x; 3)");
+
+        let mut reader = StringReader::new("1,2,");
+
+        let res = reader
+            .parse_delimited::<u32>(',')
+            .collect::<Result<Vec<_>, _>>();
+
+        assert!(res.is_err());
+        assert_eq!(reader.as_str(), "");
+    }
+}
@@ -0,0 +1,337 @@
+use core::iter::FusedIterator;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{ErrorKind, ParseError, Span, StringReader, Token};
+
+/// Custom lexer rule.
+type CustomRule<K> = Box<dyn for<'r> Fn(&mut StringReader<'r>) -> Option<K>>;
+
+/// Lexer rule.
+enum LexRule<K> {
+    Literal(String, K),
+    Run(Box<dyn Fn(char) -> bool>, K),
+    Custom(CustomRule<K>),
+}
+
+impl<K> LexRule<K>
+where
+    K: Clone,
+{
+    /// Try to consume a token using this rule and return its kind.
+    fn apply(&self, reader: &mut StringReader) -> Option<K> {
+        match self {
+            Self::Literal(literal, kind) => reader.match_str(literal).ok().map(|_| kind.clone()),
+            Self::Run(cnd, kind) => {
+                let run = reader.read_while(cnd);
+
+                Some(kind.clone()).filter(|_| !run.is_empty())
+            }
+            Self::Custom(rule) => rule(reader),
+        }
+    }
+}
+
+/// Builder of rule-based lexers.
+///
+/// Rules are registered together with token kinds. Unlike `Tokenizer`, the
+/// lexer tries all rules at each position and the rule consuming the longest
+/// part of the input wins. If multiple rules consume the same amount of
+/// input, the one registered first wins. Rules that match without consuming
+/// any input are treated as not matching. The builder can be used for
+/// lexing any number of inputs.
+///
+/// Whitespace between tokens is skipped by default.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{LexerBuilder, StringReader};
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// enum Kind {
+///     Keyword,
+///     Ident,
+///     Le,
+///     Lt,
+/// }
+///
+/// let lexer = LexerBuilder::new()
+///     .literal("if", Kind::Keyword)
+///     .run(|c| c.is_ascii_alphanumeric(), Kind::Ident)
+///     .literal("<", Kind::Lt)
+///     .literal("<=", Kind::Le);
+///
+/// let tokens = lexer
+///     .lex(StringReader::new("if iffy <= x"))
+///     .map(|t| t.map(|t| (t.kind, t.text)))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Kind::Keyword, "if"),
+///         (Kind::Ident, "iffy"),
+///         (Kind::Le, "<="),
+///         (Kind::Ident, "x"),
+///     ]
+/// );
+/// ```
+pub struct LexerBuilder<K> {
+    rules: Vec<LexRule<K>>,
+    skip_whitespace: bool,
+}
+
+impl<K> LexerBuilder<K> {
+    /// Create a new lexer builder with no rules.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            skip_whitespace: true,
+        }
+    }
+
+    /// Add a rule matching a given literal string.
+    ///
+    /// # Arguments
+    ///
+    /// * `literal` - the string
+    /// * `kind` - kind of the matched tokens
+    pub fn literal<T>(mut self, literal: T, kind: K) -> Self
+    where
+        T: Into<String>,
+    {
+        self.rules.push(LexRule::Literal(literal.into(), kind));
+        self
+    }
+
+    /// Add a rule matching the longest non-empty run of characters
+    /// satisfying a given condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    /// * `kind` - kind of the matched tokens
+    pub fn run<F>(mut self, cnd: F, kind: K) -> Self
+    where
+        F: Fn(char) -> bool + 'static,
+    {
+        self.rules.push(LexRule::Run(Box::new(cnd), kind));
+        self
+    }
+
+    /// Add a custom rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - a function that tries to consume a token and returns its
+    ///   kind on success; the reader is rewound automatically
+    pub fn rule<F>(mut self, rule: F) -> Self
+    where
+        F: for<'r> Fn(&mut StringReader<'r>) -> Option<K> + 'static,
+    {
+        self.rules.push(LexRule::Custom(Box::new(rule)));
+        self
+    }
+
+    /// Enable/disable skipping of whitespace between tokens (enabled by
+    /// default).
+    #[inline]
+    pub fn skip_whitespace(mut self, enabled: bool) -> Self {
+        self.skip_whitespace = enabled;
+        self
+    }
+
+    /// Create a lexer reading tokens from a given reader.
+    #[inline]
+    pub fn lex<'a>(&self, reader: StringReader<'a>) -> Lexer<'_, 'a, K> {
+        Lexer {
+            rules: &self.rules,
+            reader,
+            skip_whitespace: self.skip_whitespace,
+            failed: false,
+        }
+    }
+}
+
+impl<K> Default for LexerBuilder<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rule-based lexer.
+///
+/// The lexer is created by `LexerBuilder::lex()`. It yields tokens until the
+/// end of the input or until the first error. An error is returned if no
+/// rule matches the input.
+pub struct Lexer<'l, 'a, K> {
+    rules: &'l [LexRule<K>],
+    reader: StringReader<'a>,
+    skip_whitespace: bool,
+    failed: bool,
+}
+
+impl<'a, K> Lexer<'_, 'a, K>
+where
+    K: Clone,
+{
+    /// Get the underlying reader.
+    #[inline]
+    pub fn reader(&self) -> &StringReader<'a> {
+        &self.reader
+    }
+
+    /// Take the underlying reader.
+    #[inline]
+    pub fn into_reader(self) -> StringReader<'a> {
+        self.reader
+    }
+
+    /// Read the next token.
+    fn next_token(&mut self) -> Option<Result<Token<'a, K>, ParseError>> {
+        if self.skip_whitespace {
+            self.reader.skip_whitespace();
+        }
+
+        if self.reader.is_empty() {
+            return None;
+        }
+
+        let start = self.reader.checkpoint();
+
+        let mut best = None;
+        let mut best_len = 0;
+
+        for rule in self.rules {
+            if let Some(kind) = rule.apply(&mut self.reader) {
+                let end = self.reader.checkpoint();
+
+                let len = end.position().offset - start.position().offset;
+
+                if len > best_len {
+                    best = Some((kind, end));
+                    best_len = len;
+                }
+            }
+
+            self.reader.rewind(start);
+        }
+
+        if let Some((kind, end)) = best {
+            self.reader.rewind(end);
+
+            let token = Token {
+                kind,
+                text: self.reader.slice(start, end),
+                span: Span {
+                    start: start.position(),
+                    end: end.position(),
+                },
+            };
+
+            Some(Ok(token))
+        } else {
+            let err = self
+                .reader
+                .error(ErrorKind::NoMatch)
+                .with_found(self.reader.current_char());
+
+            Some(Err(err))
+        }
+    }
+}
+
+impl<'a, K> Iterator for Lexer<'_, 'a, K>
+where
+    K: Clone,
+{
+    type Item = Result<Token<'a, K>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let res = self.next_token();
+
+        if let Some(Err(_)) = res {
+            self.failed = true;
+        }
+
+        res
+    }
+}
+
+impl<K> FusedIterator for Lexer<'_, '_, K> where K: Clone {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, LexerBuilder, StringReader};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum Kind {
+        Keyword,
+        Ident,
+        Number,
+        Arrow,
+        Minus,
+    }
+
+    #[test]
+    fn test_lexer() {
+        let lexer = LexerBuilder::new()
+            .literal("fn", Kind::Keyword)
+            .run(|c| c.is_ascii_alphabetic(), Kind::Ident)
+            .rule(|r| r.read_int_inline::<u32>().ok().map(|_| Kind::Number))
+            .literal("-", Kind::Minus)
+            .literal("->", Kind::Arrow);
+
+        let mut tokens = lexer.lex(StringReader::new("fn fnord -> 12\n- ?"));
+
+        let token = tokens.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Keyword, "fn"));
+        assert_eq!(token.span.range(), 0..2);
+
+        let token = tokens.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Ident, "fnord"));
+
+        let token = tokens.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Arrow, "->"));
+        assert_eq!(token.span.range(), 9..11);
+
+        let token = tokens.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Number, "12"));
+
+        let token = tokens.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Minus, "-"));
+        assert_eq!(token.span.start.line, 2);
+
+        let err = tokens.next().unwrap().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('?'));
+        assert_eq!(err.position().offset, 17);
+
+        assert!(tokens.next().is_none());
+        assert_eq!(tokens.into_reader().as_str(), "?");
+
+        // the builder is not consumed by lexing
+        let kinds = lexer
+            .skip_whitespace(false)
+            .lex(StringReader::new("fn->x"))
+            .map(|t| t.unwrap().kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(kinds, [Kind::Keyword, Kind::Arrow, Kind::Ident]);
+    }
+}
@@ -27,7 +27,7 @@
 //!         _ => return Err(HttpParseError),
 //!     }
 //!
-//!     let status_code = reader.read_u16()?;
+//!     let status_code = reader.parse_word::<u16>()?;
 //!
 //!     Ok((status_code, reader.as_str().trim()))
 //! }
@@ -53,376 +53,3627 @@
 //! assert_eq!(status_msg, "Not Found");
 //! ```
 
-use std::{
-    error::Error,
-    fmt::{self, Display, Formatter},
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+/// Evaluate a given reader operation and emit a trace event with the method
+/// name, its argument, the starting position and the outcome (if the
+/// `tracing` feature is enabled).
+macro_rules! traced {
+    ($reader:expr, $method:literal, $op:expr) => {{
+        #[cfg(feature = "tracing")]
+        let position = $reader.position;
+
+        let res = $op;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(method = $method, %position, outcome = ?res);
+
+        res
+    }};
+    ($reader:expr, $method:literal, $arg:expr, $op:expr) => {{
+        #[cfg(feature = "tracing")]
+        let position = $reader.position;
+
+        let res = $op;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(method = $method, arg = ?$arg, %position, outcome = ?res);
+
+        res
+    }};
+}
+
+#[cfg(feature = "serde")]
+pub mod de;
+
+pub mod http;
+
+mod boolean;
+mod bytes;
+
+#[cfg(feature = "caseless")]
+mod casefold;
+
+mod chained;
+mod combinator;
+mod comments;
+
+#[cfg(feature = "alloc")]
+mod csv;
+
+mod delimiters;
+mod encoding;
+mod error;
+mod field;
+
+#[cfg(feature = "unicode-segmentation")]
+mod grapheme;
+
+mod ident;
+mod iter;
+
+#[cfg(feature = "alloc")]
+mod lexer;
+
+mod literal;
+mod mime;
+mod net;
+
+#[cfg(feature = "nom")]
+mod nom;
+
+mod num;
+mod parse;
+mod pattern;
+
+#[cfg(feature = "alloc")]
+mod query;
+
+mod recovery;
+mod semver;
+
+#[cfg(feature = "alloc")]
+mod shell;
+
+#[cfg(feature = "std")]
+mod stream;
+
+#[cfg(feature = "std")]
+mod time;
+
+#[cfg(feature = "alloc")]
+mod tokenizer;
+
+mod units;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+use core::{
+    cell::Cell,
+    fmt::{self, Debug, Display, Formatter},
     num::{ParseFloatError, ParseIntError},
+    ops::{Range, RangeBounds},
     result,
     str::{Chars, FromStr},
 };
 
-/// String reader error.
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[doc(hidden)]
+pub use self::pattern::__private;
+
+#[cfg(feature = "std")]
+pub use self::stream::{StreamError, StreamingReader};
+
+#[cfg(feature = "async")]
+pub use self::stream::AsyncStreamingReader;
+
+#[cfg(feature = "alloc")]
+pub use self::{
+    csv::CsvRecords,
+    ident::KeywordSet,
+    lexer::{Lexer, LexerBuilder},
+    query::QueryPairs,
+    tokenizer::{Token, Tokenizer},
+};
+
+#[cfg(feature = "derive")]
+pub use str_reader_derive::FromReader;
+
+// Allow the derive macro to refer to this crate in tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as str_reader;
+
+pub use self::{
+    boolean::BoolOptions,
+    bytes::BytesReader,
+    chained::{ChainedReader, ChainedStr},
+    combinator::Alt,
+    comments::Comments,
+    delimiters::Delimiters,
+    error::{
+        ErrorKind, Expected, ExpectedSet, FloatErrorKind, InlineStr, NumberError, ParseError,
+        WithSource,
+    },
+    field::Padding,
+    iter::{CharsByRef, Delimited, Lines, Words},
+    mime::MediaType,
+    num::{NumberOptions, Overflow, ReadableFloat, ReadableInt},
+    parse::FromReader,
+    recovery::Diagnostic,
+    semver::SemanticVersion,
+};
+
+/// Position within the input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// Line number (starting from 1).
+    pub line: usize,
+    /// Column number in characters (starting from 1).
+    pub column: usize,
+    /// Byte offset from the beginning of the input (starting from 0).
+    pub offset: usize,
+}
+
+impl Position {
+    /// Position at the beginning of an input.
+    const START: Self = Self {
+        line: 1,
+        column: 1,
+        offset: 0,
+    };
+
+    /// Advance the position by a given character.
+    #[inline]
+    fn advance_char(&mut self, c: char) {
+        self.offset += c.len_utf8();
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+    }
+
+    /// Advance the position by given bytes. Columns are counted in bytes.
+    fn advance_bytes(&mut self, b: &[u8]) {
+        self.offset += b.len();
+
+        if let Some(index) = b.iter().rposition(|&b| b == b'\n') {
+            self.line += b.iter().filter(|&&b| b == b'\n').count();
+            self.column = b.len() - index;
+        } else {
+            self.column += b.len();
+        }
+    }
+
+    /// Advance the position by a given string.
+    fn advance_str(&mut self, s: &str) {
+        self.offset += s.len();
+
+        if let Some(index) = s.rfind('\n') {
+            self.line += s.bytes().filter(|&b| b == b'\n').count();
+            self.column = s[index + 1..].chars().count() + 1;
+        } else {
+            self.column += s.chars().count();
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Part of the input between two positions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Start position (inclusive).
+    pub start: Position,
+    /// End position (exclusive).
+    pub end: Position,
+}
+
+impl Span {
+    /// Get the byte range of the span.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// Get length of the span in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    /// Check if the span is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start.offset == self.end.offset
+    }
+}
+
+/// Saved state of a string reader.
+///
+/// A checkpoint can be used to rewind the reader back to the state it was in
+/// when the checkpoint was created. See `StringReader::checkpoint()` and
+/// `StringReader::rewind()`. Checkpoints can be also used for extracting the
+/// input consumed between them (see `StringReader::slice()`).
+#[derive(Debug, Copy, Clone)]
+pub struct Checkpoint<'a> {
+    rest: &'a str,
+    position: Position,
+}
+
+impl<'a> Checkpoint<'a> {
+    /// Get the reader position at the time the checkpoint was created.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Start of a capture.
+///
+/// A capture can be used for retrieving the input consumed since the capture
+/// was started. See `StringReader::start_capture()`.
 #[derive(Debug, Copy, Clone)]
-pub enum ParseError {
-    EmptyInput,
-    NoMatch,
+pub struct Capture<'a> {
+    start: Checkpoint<'a>,
+}
+
+impl<'a> Capture<'a> {
+    /// Get the reader position at the time the capture was started.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.start.position
+    }
+
+    /// Get the input consumed by a given reader since the capture was
+    /// started.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - the reader the capture was started on
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the capture was started on a different reader
+    /// or if the reader was rewound to a state before the capture was
+    /// started.
+    #[inline]
+    pub fn finish(&self, reader: &StringReader<'a>) -> &'a str {
+        reader.slice(self.start, reader.checkpoint())
+    }
+}
+
+/// String reader.
+pub struct StringReader<'a> {
+    origin: &'a str,
+    input: Chars<'a>,
+    current: Option<char>,
+    position: Position,
+    delimiters: Delimiters,
+    comments: Option<Comments>,
+    strict_words: bool,
+    tracked: Cell<Option<ExpectedSet>>,
 }
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
-        let msg = match *self {
-            Self::EmptyInput => "input is empty",
-            Self::NoMatch => "the input does not match",
-        };
+impl<'a> StringReader<'a> {
+    /// Create a new reader for a given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - input string or an object that can be referenced as a
+    ///   string
+    pub fn new<T>(input: &'a T) -> Self
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        let origin = input.as_ref();
+        let input = origin.chars();
+
+        // We do not want to advance the input just yet. If we did that the
+        // string matching methods would not work.
+        let current = input.clone().next();
+
+        Self {
+            origin,
+            input,
+            current,
+            position: Position::START,
+            delimiters: Delimiters::Whitespace,
+            comments: None,
+            strict_words: false,
+            tracked: Cell::new(None),
+        }
+    }
+
+    /// Use a given definition of word delimiters. By default, words are
+    /// delimited by Unicode whitespace characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiters` - word delimiters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{Delimiters, StringReader};
+    ///
+    /// let mut reader = StringReader::new("a,b;;c")
+    ///     .with_delimiters(Delimiters::Custom(|c| c == ',' || c == ';'));
+    ///
+    /// assert_eq!(reader.read_word(), "a");
+    /// assert_eq!(reader.read_word(), "b");
+    /// assert_eq!(reader.read_word(), "c");
+    /// ```
+    #[inline]
+    pub fn with_delimiters(mut self, delimiters: Delimiters) -> Self {
+        self.delimiters = delimiters;
+        self
+    }
+
+    /// Get the current definition of word delimiters.
+    #[inline]
+    pub fn delimiters(&self) -> Delimiters {
+        self.delimiters
+    }
+
+    /// Set the position of the beginning of the remaining input. It is
+    /// useful when the input is a part of a larger document. Positions and
+    /// spans reported by the reader will be relative to the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - position of the input within the document
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let document = "name = foo\nport = 80x";
+    ///
+    /// let mut reader = StringReader::new(document);
+    ///
+    /// reader.skip_line();
+    /// reader.match_str("port = ").unwrap();
+    ///
+    /// let position = reader.position();
+    /// let value = reader.read_line();
+    ///
+    /// let mut value = StringReader::new(value).with_position(position);
+    ///
+    /// value.match_str("80").unwrap();
+    ///
+    /// let err = value.match_char(';').unwrap_err();
+    ///
+    /// assert_eq!(err.position().to_string(), "2:10");
+    /// ```
+    #[inline]
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the byte offset of the beginning of the remaining input. Unlike
+    /// `with_position()`, the line and column numbers are kept as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - byte offset of the input within the document
+    #[inline]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.position.offset = offset;
+        self
+    }
+
+    /// Disable implicit skipping of whitespace (word delimiters) and comments
+    /// before words. In the strict mode, a word must start right at the
+    /// current position, so an unexpected delimiter results in an empty word
+    /// instead of being silently swallowed. This affects `read_word()`,
+    /// `peek_word()`, `parse_word()` and all methods built on top of them
+    /// (e.g. `read_u32()`) as well as numbers and words read by `parse()`.
+    /// Note that the `words()` iterator stops at the first delimiter in the
+    /// strict mode. Delimiters can still be skipped explicitly using
+    /// `skip_whitespace()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("10 20  30").with_strict_words();
+    ///
+    /// assert_eq!(reader.read_u32(), Ok(10));
+    ///
+    /// reader.match_char(' ').unwrap();
+    ///
+    /// assert_eq!(reader.read_u32(), Ok(20));
+    ///
+    /// reader.match_char(' ').unwrap();
+    ///
+    /// assert!(reader.read_u32().is_err());
+    /// assert_eq!(reader.as_str(), " 30");
+    /// ```
+    #[inline]
+    pub fn with_strict_words(mut self) -> Self {
+        self.strict_words = true;
+        self
+    }
+
+    /// Enable tracking of expected input. If enabled, the reader records
+    /// what was expected whenever a character or string cannot be matched.
+    /// Only expectations at the furthest position reached are kept, so after
+    /// a failed parse they describe all alternatives that could have
+    /// continued the input. Rewinding the reader does not discard the
+    /// recorded expectations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{Expected, StringReader};
+    ///
+    /// let mut reader = StringReader::new("1 * 2").with_expectation_tracking();
+    ///
+    /// reader.read_word();
+    /// reader.skip_whitespace();
+    ///
+    /// let res = reader.alt((
+    ///     |r: &mut StringReader| r.match_char('+'),
+    ///     |r: &mut StringReader| r.match_char('-'),
+    ///     |r: &mut StringReader| r.track_expected(Expected::Description("digit")),
+    /// ));
+    ///
+    /// assert!(res.is_err());
+    ///
+    /// let expected = reader.expected_set().unwrap();
+    ///
+    /// assert_eq!(
+    ///     expected.to_string(),
+    ///     "expected one of '+', '-', digit at 1:3, found '*'"
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_expectation_tracking(self) -> Self {
+        self.tracked.set(Some(ExpectedSet::new()));
+        self
+    }
+
+    /// Get expectations recorded at the furthest position reached. `None`
+    /// is returned if expectation tracking is not enabled (see
+    /// `with_expectation_tracking()`).
+    #[inline]
+    pub fn expected_set(&self) -> Option<ExpectedSet> {
+        self.tracked.get()
+    }
+
+    /// Record a given expectation at the current position and return the
+    /// corresponding error. The expectation is recorded only if expectation
+    /// tracking is enabled. The method can be used for reporting failures of
+    /// custom parsing functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - expected input
+    pub fn track_expected<T>(&self, expected: Expected) -> Result<T, ParseError> {
+        let kind = if self.is_empty() {
+            ErrorKind::EmptyInput
+        } else {
+            ErrorKind::NoMatch
+        };
+
+        let err = self
+            .error(kind)
+            .with_expected(expected)
+            .with_found(self.current_char());
+
+        self.track(Err(err))
+    }
+
+    /// Record the expectation of a given error (if any) and return the
+    /// result unchanged. The expectation is recorded only if expectation
+    /// tracking is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `res` - result of a parsing function
+    pub fn track<T>(&self, res: Result<T, ParseError>) -> Result<T, ParseError> {
+        if let Err(err) = res.as_ref() {
+            if let Some(mut tracked) = self.tracked.get() {
+                tracked.record(err);
+
+                self.tracked.set(Some(tracked));
+            }
+        }
+
+        res
+    }
+
+    /// Get the current character (if any) without advancing the input.
+    #[inline]
+    pub fn current_char(&self) -> Option<char> {
+        self.current
+    }
+
+    /// Get up to `n` next characters without advancing the input. The
+    /// returned string will be shorter if there are not enough characters
+    /// left in the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of characters
+    pub fn peek_str(&self, n: usize) -> &'a str {
+        let input = self.input.as_str();
+
+        let index = input
+            .char_indices()
+            .nth(n)
+            .map(|(index, _)| index)
+            .unwrap_or(input.len());
+
+        &input[..index]
+    }
+
+    /// Check if the input starts with a given string. The input is not
+    /// advanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - string to look for
+    #[inline]
+    pub fn starts_with(&self, val: &str) -> bool {
+        self.input.as_str().starts_with(val)
+    }
+
+    /// Get the current position within the input.
+    #[inline]
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Save the current state of the reader.
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint<'a> {
+        Checkpoint {
+            rest: self.input.as_str(),
+            position: self.position,
+        }
+    }
+
+    /// Start capturing the consumed input. The input consumed by any methods
+    /// of the reader since this point can be retrieved using
+    /// `Capture::finish()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("x = -12.5e3; y");
+    ///
+    /// reader.match_str("x = ").unwrap();
+    ///
+    /// let capture = reader.start_capture();
+    ///
+    /// reader.match_one_of("+-").unwrap();
+    /// reader.read_digits(10);
+    /// reader.match_char('.').unwrap();
+    /// reader.read_until_char(';');
+    ///
+    /// assert_eq!(capture.finish(&reader), "-12.5e3");
+    /// ```
+    #[inline]
+    pub fn start_capture(&self) -> Capture<'a> {
+        Capture {
+            start: self.checkpoint(),
+        }
+    }
+
+    /// Restore a previously saved state of the reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint` - a checkpoint created by this reader
+    pub fn rewind(&mut self, checkpoint: Checkpoint<'a>) {
+        self.input = checkpoint.rest.chars();
+        self.current = self.input.clone().next();
+        self.position = checkpoint.position;
+    }
+
+    /// Get part of the input between two checkpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - checkpoint at the beginning of the slice
+    /// * `to` - checkpoint at the end of the slice
+    ///
+    /// # Panics
+    ///
+    /// The method panics if any of the checkpoints was not created by this
+    /// reader or if `from` comes after `to`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("-12.5e3 m");
+    ///
+    /// let start = reader.checkpoint();
+    ///
+    /// reader.read_int_inline::<i32>().unwrap();
+    /// reader.match_char('.').unwrap();
+    /// reader.read_int_inline::<u32>().unwrap();
+    /// reader.match_char('e').unwrap();
+    /// reader.read_int_inline::<i32>().unwrap();
+    ///
+    /// let end = reader.checkpoint();
+    ///
+    /// assert_eq!(reader.slice(start, end), "-12.5e3");
+    /// ```
+    pub fn slice(&self, from: Checkpoint<'a>, to: Checkpoint<'a>) -> &'a str {
+        let offset = |checkpoint: Checkpoint| {
+            let origin = self.origin.as_ptr() as usize;
+            let rest = checkpoint.rest.as_ptr() as usize;
+
+            assert!(
+                rest >= origin && rest - origin + checkpoint.rest.len() == self.origin.len(),
+                "checkpoint created by a different reader"
+            );
+
+            rest - origin
+        };
+
+        let start = offset(from);
+        let end = offset(to);
+
+        assert!(start <= end, "checkpoints out of order");
+
+        &self.origin[start..end]
+    }
+
+    /// Run a given parsing function and rewind the reader to its original
+    /// state if the function fails. The input consumed by the function is
+    /// kept only if the function succeeds. Transactions can be nested.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - parsing function
+    pub fn transaction<F, T, E>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let checkpoint = self.checkpoint();
+
+        let res = f(self);
+
+        if res.is_err() {
+            self.rewind(checkpoint);
+        }
+
+        res
+    }
+
+    /// Run a given parsing function and return its result together with the
+    /// span of the input it consumed. Note that the span includes everything
+    /// the function consumed (e.g. leading whitespace skipped by
+    /// `read_word()`).
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - parsing function
+    pub fn spanned<F, T, E>(&mut self, f: F) -> Result<(T, Span), E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let start = self.position;
+
+        let res = f(self)?;
+
+        let span = Span {
+            start,
+            end: self.position,
+        };
+
+        Ok((res, span))
+    }
+
+    /// Get the next character or return an error if the input is empty.
+    pub fn read_char(&mut self) -> Result<char, ParseError> {
+        traced!(self, "read_char", {
+            if let Some(c) = self.input.next() {
+                self.position.advance_char(c);
+
+                // Peek for the next character without advancing the input.
+                self.current = self.input.clone().next();
+
+                Ok(c)
+            } else {
+                Err(self.error(ErrorKind::EmptyInput))
+            }
+        })
+    }
+
+    /// Match a given character to the input and, if successful, advance the
+    /// input by exactly one character. An error is returned if the input
+    /// character does not match with the given one or if the input is empty.
+    /// The error contains the expected character, the character found in the
+    /// input and the position, so it can be reported to the user directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - expected character
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("(1]");
+    ///
+    /// reader.match_char('(').unwrap();
+    /// reader.read_digits(10);
+    ///
+    /// let err = reader.match_char(')').unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "the input does not match at 1:3: expected ')', found ']'"
+    /// );
+    /// ```
+    #[inline]
+    pub fn match_char(&mut self, expected: char) -> Result<(), ParseError> {
+        traced!(
+            self,
+            "match_char",
+            expected,
+            self.match_char_by(expected, |a, b| a == b)
+        )
+    }
+
+    /// Match a given character to the input ignoring ASCII case differences
+    /// and, if successful, advance the input by exactly one character.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - expected character
+    #[inline]
+    pub fn match_char_ignore_ascii_case(&mut self, expected: char) -> Result<(), ParseError> {
+        traced!(
+            self,
+            "match_char_ignore_ascii_case",
+            expected,
+            self.match_char_by(expected, |a, b| a.eq_ignore_ascii_case(&b))
+        )
+    }
+
+    /// Match a given character to the input ignoring case differences and, if
+    /// successful, advance the input by exactly one character. Characters are
+    /// compared using their Unicode lowercase mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - expected character
+    #[inline]
+    pub fn match_char_ignore_case(&mut self, expected: char) -> Result<(), ParseError> {
+        traced!(
+            self,
+            "match_char_ignore_case",
+            expected,
+            self.match_char_by(expected, eq_ignore_case)
+        )
+    }
+
+    /// Match the current character to a given set of characters and, if
+    /// successful, advance the input by exactly one character and return the
+    /// matched character.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - set of expected characters
+    pub fn match_one_of(&mut self, set: &str) -> Result<char, ParseError> {
+        traced!(self, "match_one_of", set, {
+            if let Some(c) = self.peek_one_of(set) {
+                self.skip_char();
+
+                Ok(c)
+            } else {
+                let kind = if self.is_empty() {
+                    ErrorKind::EmptyInput
+                } else {
+                    ErrorKind::NoMatch
+                };
+
+                let err = self
+                    .error(kind)
+                    .with_expected(Expected::OneOf(InlineStr::new(set)))
+                    .with_found(self.current_char());
+
+                self.track(Err(err))
+            }
+        })
+    }
+
+    /// Match the current character using a given predicate and, if
+    /// successful, advance the input by exactly one character and return the
+    /// matched character. An error is returned if the predicate does not hold
+    /// or if the input is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - a closure that takes a single character and returns
+    ///   true/false
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("a1");
+    ///
+    /// assert_eq!(reader.match_char_if(|c| c.is_alphabetic()), Ok('a'));
+    /// assert!(reader.match_char_if(|c| c.is_alphabetic()).is_err());
+    /// assert_eq!(reader.as_str(), "1");
+    /// ```
+    pub fn match_char_if<F>(&mut self, pred: F) -> Result<char, ParseError>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        traced!(self, "match_char_if", {
+            match self.current_char() {
+                Some(c) if pred(c) => {
+                    self.skip_char();
+
+                    Ok(c)
+                }
+                c => {
+                    let kind = if c.is_some() {
+                        ErrorKind::NoMatch
+                    } else {
+                        ErrorKind::EmptyInput
+                    };
+
+                    let err = self
+                        .error(kind)
+                        .with_expected(Expected::Description("matching character"))
+                        .with_found(c);
+
+                    self.track(Err(err))
+                }
+            }
+        })
+    }
+
+    /// Get the current character if it belongs to a given set of characters.
+    /// The input is not advanced.
+    ///
+    /// # Arguments
+    ///
+    /// * `set` - set of expected characters
+    #[inline]
+    pub fn peek_one_of(&self, set: &str) -> Option<char> {
+        self.current_char().filter(|&c| set.contains(c))
+    }
+
+    /// Skip one character.
+    pub fn skip_char(&mut self) {
+        // Remove the current character.
+        if let Some(c) = self.input.next() {
+            self.position.advance_char(c);
+        }
+
+        // Peek for the next character without advancing the input.
+        self.current = self.input.clone().next();
+    }
+
+    /// Read exactly `n` characters (not bytes) and return them. An error is
+    /// returned if there are fewer characters left. The input won't be
+    /// advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of characters
+    pub fn read_exact(&mut self, n: usize) -> Result<&'a str, ParseError> {
+        let s = self.peek_str(n);
+
+        if s.chars().count() < n {
+            let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                .with_expected(Expected::Description("more characters"));
+
+            return Err(err);
+        }
+
+        Ok(self.split_to(s.len()))
+    }
+
+    /// Split off the next `n` characters (not bytes) and return a reader for
+    /// them. This reader will be advanced past the characters. The returned
+    /// reader inherits settings of this reader and cannot read past the `n`
+    /// characters. An error is returned if there are fewer characters left.
+    /// The input won't be advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of characters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("5:hello world");
+    ///
+    /// let len = reader.read_int_inline().unwrap();
+    ///
+    /// reader.match_char(':').unwrap();
+    ///
+    /// let mut payload = reader.take(len).unwrap();
+    ///
+    /// assert_eq!(payload.read_word(), "hello");
+    /// assert!(payload.is_empty());
+    /// assert_eq!(reader.as_str(), " world");
+    /// ```
+    pub fn take(&mut self, n: usize) -> Result<Self, ParseError> {
+        let position = self.position;
+
+        let s = self.read_exact(n)?;
+
+        Ok(self.sub_reader(s, position))
+    }
+
+    /// Split off the next `n` bytes and return a reader for them. See
+    /// `take()` for more info. An error is returned if there are fewer bytes
+    /// left or if the `n` bytes would end in the middle of a character.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of bytes
+    pub fn take_bytes(&mut self, n: usize) -> Result<Self, ParseError> {
+        let input = self.input.as_str();
+
+        if n > input.len() {
+            let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                .with_expected(Expected::Description("more bytes"));
+
+            return Err(err);
+        } else if !input.is_char_boundary(n) {
+            let index = (0..n)
+                .rev()
+                .find(|&i| input.is_char_boundary(i))
+                .unwrap_or(0);
+
+            let rest = &input[index..];
+
+            let err = ParseError::new(ErrorKind::InvalidValue, self.position_of(rest))
+                .with_expected(Expected::Description("character boundary"))
+                .with_found(rest.chars().next());
+
+            return Err(err);
+        }
+
+        let position = self.position;
+
+        let s = self.split_to(n);
+
+        Ok(self.sub_reader(s, position))
+    }
+
+    /// Skip the UTF-8 byte order mark (`U+FEFF`) if it is present at the
+    /// current position. The method returns true if the mark was skipped.
+    /// The mark advances only the byte offset of the current position, so
+    /// the following character is still reported at the same column.
+    pub fn skip_bom(&mut self) -> bool {
+        if self.current != Some('\u{feff}') {
+            return false;
+        }
+
+        self.input.next();
+        self.current = self.input.clone().next();
+        self.position.offset += '\u{feff}'.len_utf8();
+
+        true
+    }
+
+    /// Skip all whitespace characters (or, more precisely, all word
+    /// delimiters). Comments are skipped as well if the reader was configured
+    /// to do so (see `with_comments()`).
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.trim_start(self.input.as_str());
+
+        self.advance_to(rest);
+    }
+
+    /// Match a given string to the input and, if successful, advance the input
+    /// by the length of the given string. An error is returned if the input
+    /// does not start with the given string. The error will point to the first
+    /// character that does not match and it will contain the expected string
+    /// and the character found in the input, so it can be reported to the
+    /// user directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - expected string
+    pub fn match_str(&mut self, val: &str) -> Result<(), ParseError> {
+        traced!(self, "match_str", val, {
+            let input = self.input.as_str();
+
+            if input.starts_with(val) {
+                let (_, rest) = input.split_at(val.len());
+
+                self.advance_to(rest);
+
+                Ok(())
+            } else {
+                // Use the slow path to construct a detailed error.
+                self.match_str_by(val, |a, b| a == b)
+            }
+        })
+    }
+
+    /// Match the longest string from a given table of alternatives and, if
+    /// successful, advance the input past the matched string and return the
+    /// associated value. If multiple alternatives of the same length match,
+    /// the first one wins. Empty alternatives never match. An error is
+    /// returned if none of the alternatives matches. If expectation tracking
+    /// is enabled, all alternatives are recorded as expected.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - alternatives and their values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// enum Op {
+    ///     Lt,
+    ///     Shl,
+    ///     ShlEq,
+    /// }
+    ///
+    /// let ops = [("<", Op::Lt), ("<<", Op::Shl), ("<<=", Op::ShlEq)];
+    ///
+    /// let mut reader = StringReader::new("<<<<=");
+    ///
+    /// assert_eq!(reader.match_longest(&ops), Ok(Op::Shl));
+    /// assert_eq!(reader.match_longest(&ops), Ok(Op::ShlEq));
+    /// assert!(reader.match_longest(&ops).is_err());
+    /// ```
+    pub fn match_longest<K>(&mut self, table: &[(&str, K)]) -> Result<K, ParseError>
+    where
+        K: Clone,
+    {
+        // The values are not required to implement `Debug`, so only the
+        // index of the matched alternative is traced.
+        let index = traced!(self, "match_longest", self.longest_match(table))?;
+
+        let (_, value) = &table[index];
+
+        Ok(value.clone())
+    }
+
+    /// Match a given string to the input ignoring ASCII case differences and,
+    /// if successful, advance the input by the length of the given string.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - expected string
+    #[inline]
+    pub fn match_str_ignore_ascii_case(&mut self, val: &str) -> Result<(), ParseError> {
+        traced!(
+            self,
+            "match_str_ignore_ascii_case",
+            val,
+            self.match_str_by(val, |a, b| a.eq_ignore_ascii_case(&b))
+        )
+    }
+
+    /// Match a given string to the input ignoring case differences and, if
+    /// successful, advance the input by the number of characters of the given
+    /// string. Characters are compared using their Unicode lowercase mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - expected string
+    #[inline]
+    pub fn match_str_ignore_case(&mut self, val: &str) -> Result<(), ParseError> {
+        traced!(
+            self,
+            "match_str_ignore_case",
+            val,
+            self.match_str_by(val, eq_ignore_case)
+        )
+    }
+
+    /// Read until a given condition is true or until the end of the input and
+    /// return the string.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    pub fn read_until<F>(&mut self, cnd: F) -> &'a str
+    where
+        F: FnMut(char) -> bool,
+    {
+        let rest = self.input.as_str();
+
+        let index = rest.find(cnd).unwrap_or(rest.len());
+
+        self.split_to(index)
+    }
+
+    /// Read until a given character or until the end of the input and return
+    /// the string. The character is not consumed. This is a faster
+    /// alternative to `read_until()` for the common case of scanning to a
+    /// single delimiter. The scan uses `memchr` if the `memchr` feature is
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - terminating character
+    pub fn read_until_char(&mut self, c: char) -> &'a str {
+        let rest = self.input.as_str();
+
+        let index = find_char(rest, c).unwrap_or(rest.len());
+
+        self.split_to(index)
+    }
+
+    /// Read until a given condition is true for an ASCII character or until
+    /// the end of the input and return the string. The input is scanned
+    /// byte-wise and the condition is evaluated only for ASCII characters;
+    /// non-ASCII characters never satisfy it. This is a faster alternative to
+    /// `read_until()` for ASCII delimiters.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single ASCII character (as a byte)
+    ///   and returns true/false
+    pub fn read_until_ascii<F>(&mut self, mut cnd: F) -> &'a str
+    where
+        F: FnMut(u8) -> bool,
+    {
+        let rest = self.input.as_str();
+
+        let index = rest
+            .bytes()
+            .position(|b| b.is_ascii() && cnd(b))
+            .unwrap_or(rest.len());
+
+        self.split_to(index)
+    }
+
+    /// Read while a given condition is true and return the string.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    pub fn read_while<F>(&mut self, mut cnd: F) -> &'a str
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.read_until(|c| !cnd(c))
+    }
+
+    /// Skip all characters while a given condition is true and return the
+    /// number of skipped characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    pub fn skip_while<F>(&mut self, mut cnd: F) -> usize
+    where
+        F: FnMut(char) -> bool,
+    {
+        let mut count = 0;
+
+        self.read_until(|c| {
+            let stop = !cnd(c);
+
+            if !stop {
+                count += 1;
+            }
+
+            stop
+        });
+
+        count
+    }
+
+    /// Skip all characters until a given condition is true or until the end
+    /// of the input and return the number of skipped characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    #[inline]
+    pub fn skip_until<F>(&mut self, mut cnd: F) -> usize
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.skip_while(|c| !cnd(c))
+    }
+
+    /// Skip all characters until a given terminator or until the end of the
+    /// input and return the number of skipped characters. The terminator is
+    /// not consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - terminating string
+    #[inline]
+    pub fn skip_until_str(&mut self, terminator: &str) -> usize {
+        self.read_until_str(terminator).chars().count()
+    }
+
+    /// Read until a given terminator or until the end of the input and return
+    /// the string. The terminator is not consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - terminating string
+    pub fn read_until_str(&mut self, terminator: &str) -> &'a str {
+        let rest = self.input.as_str();
+
+        let index = rest.find(terminator).unwrap_or(rest.len());
+
+        self.split_to(index)
+    }
+
+    /// Read until a given terminator, skip the terminator and return the string
+    /// before the terminator. An error is returned if there is no terminator
+    /// in the input. The input won't be advanced in such case.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminator` - terminating string
+    pub fn read_until_str_and_skip(&mut self, terminator: &str) -> Result<&'a str, ParseError> {
+        let rest = self.input.as_str();
+
+        if let Some(index) = rest.find(terminator) {
+            let res = self.split_to(index);
+
+            self.split_to(terminator.len());
+
+            Ok(res)
+        } else {
+            let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                .with_expected(Expected::Str(InlineStr::new(terminator)));
+
+            Err(err)
+        }
+    }
+
+    /// Read the rest of the current line and return it. The line terminator
+    /// (either `\n` or `\r\n`) is consumed but it is not included in the
+    /// returned string. The last line does not need to be terminated.
+    pub fn read_line(&mut self) -> &'a str {
+        let rest = self.input.as_str();
+
+        if let Some(index) = rest.find('\n') {
+            let line = self.split_to(index);
+
+            self.skip_char();
+
+            line.strip_suffix('\r').unwrap_or(line)
+        } else {
+            self.split_to(rest.len())
+        }
+    }
+
+    /// Skip the rest of the current line including the line terminator.
+    #[inline]
+    pub fn skip_line(&mut self) {
+        self.read_line();
+    }
+
+    /// Read a string enclosed in a given pair of delimiters and return the
+    /// string between them. The input must start with the opening delimiter.
+    /// Nested pairs of delimiters are included in the returned string, e.g.
+    /// reading `(a (b) c) d` returns `a (b) c`. Both the opening and the
+    /// closing delimiters are consumed. The input won't be advanced if there is
+    /// no matching closing delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - opening delimiter
+    /// * `close` - closing delimiter
+    pub fn read_balanced(&mut self, open: char, close: char) -> Result<&'a str, ParseError> {
+        let start = self.checkpoint();
+
+        self.match_char(open)?;
+
+        let inner = self.input.as_str();
+
+        let mut depth = 0usize;
+
+        let end = inner.char_indices().find_map(|(index, c)| {
+            if c == close {
+                if depth == 0 {
+                    return Some(index);
+                }
+
+                depth -= 1;
+            } else if c == open {
+                depth += 1;
+            }
+
+            None
+        });
+
+        if let Some(end) = end {
+            let res = self.split_to(end);
+
+            self.skip_char();
+
+            Ok(res)
+        } else {
+            let err = ParseError::new(ErrorKind::EmptyInput, self.end_position())
+                .with_expected(Expected::Char(close));
+
+            self.rewind(start);
+
+            Err(err)
+        }
+    }
+
+    /// Read one word from the input and return it. A word ends with the first
+    /// whitespace character (word delimiter) or with the end of the input. The
+    /// method skips all initial whitespace characters (if any) unless the
+    /// strict mode is enabled (see `with_strict_words()`).
+    #[inline]
+    pub fn read_word(&mut self) -> &'a str {
+        traced!(self, "read_word", {
+            let rest = self.word_start();
+
+            self.advance_to(rest);
+
+            let index = self.delimiters.find(rest).unwrap_or(rest.len());
+
+            self.split_to(index)
+        })
+    }
+
+    /// Read one word delimited by characters satisfying a given condition.
+    /// The method skips all initial delimiters (if any) and reads until the
+    /// next delimiter or until the end of the input. The delimiter following
+    /// the word is not consumed. Unlike `read_word()`, comments are not
+    /// skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary` - a closure that takes a single character and returns
+    ///   true if the character is a delimiter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("::usr:bin:");
+    ///
+    /// assert_eq!(reader.read_word_with(|c| c == ':'), "usr");
+    /// assert_eq!(reader.read_word_with(|c| c == ':'), "bin");
+    /// assert_eq!(reader.read_word_with(|c| c == ':'), "");
+    /// assert!(reader.is_empty());
+    /// ```
+    pub fn read_word_with<F>(&mut self, mut boundary: F) -> &'a str
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.skip_while(&mut boundary);
+        self.read_until(boundary)
+    }
+
+    /// Read one word from the input, convert it to lowercase and store it in
+    /// a given buffer. It allows case-insensitive processing of words without
+    /// allocations. The Unicode lowercase mapping is used, so the converted
+    /// word may differ in length from the original one. An error is returned
+    /// if the converted word does not fit into the buffer. The input won't be
+    /// advanced in such case. See `read_word()` for more info.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - buffer for the converted word
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("GET Index.HTML");
+    /// let mut buf = [0u8; 8];
+    ///
+    /// assert_eq!(reader.read_word_lowercase_into(&mut buf), Ok("get"));
+    /// assert!(reader.read_word_lowercase_into(&mut buf).is_err());
+    /// assert_eq!(reader.read_word(), "Index.HTML");
+    /// ```
+    pub fn read_word_lowercase_into<'b>(
+        &mut self,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, ParseError> {
+        let (word, rest) = self.first_word();
+
+        let mut len = 0;
+
+        for c in word.chars().flat_map(char::to_lowercase) {
+            let end = len + c.len_utf8();
+
+            if end > buf.len() {
+                let err =
+                    ParseError::new(ErrorKind::InvalidValue, self.position_of(self.word_start()))
+                        .with_expected(Expected::Description("shorter word"))
+                        .with_found(word.chars().next());
+
+                return Err(err);
+            }
+
+            c.encode_utf8(&mut buf[len..end]);
+
+            len = end;
+        }
+
+        self.advance_to(rest);
+
+        // The buffer contains only complete UTF-8 sequences.
+        Ok(core::str::from_utf8(&buf[..len]).unwrap_or(""))
+    }
+
+    /// Get the next word without advancing the input. See `read_word()` for
+    /// more info.
+    #[inline]
+    pub fn peek_word(&self) -> &'a str {
+        self.first_word().0
+    }
+
+    /// Read the next word and parse it. The input won't be advanced if the
+    /// word cannot be parsed.
+    pub fn parse_word<T>(&mut self) -> Result<T, T::Err>
+    where
+        T: FromStr,
+    {
+        let (word, rest) = self.first_word();
+
+        let parsed = word.parse()?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read one word from the input and return it together with its span.
+    /// The span does not include the skipped delimiters. See `read_word()`
+    /// for more info.
+    pub fn read_word_spanned(&mut self) -> (&'a str, Span) {
+        let (word, rest) = self.first_word();
+
+        let span = self.word_span(word, rest);
+
+        self.advance_to(rest);
+
+        (word, span)
+    }
+
+    /// Read the next word, parse it and return the parsed value together
+    /// with the span of the word. The input won't be advanced if the word
+    /// cannot be parsed.
+    pub fn parse_word_spanned<T>(&mut self) -> Result<(T, Span), T::Err>
+    where
+        T: FromStr,
+    {
+        let (word, rest) = self.first_word();
+
+        let parsed = word.parse()?;
+
+        let span = self.word_span(word, rest);
+
+        self.advance_to(rest);
+
+        Ok((parsed, span))
+    }
+
+    /// Read the next `N` words and parse them into an array. The input won't
+    /// be advanced if any of the words cannot be parsed. Values separated by
+    /// other characters than whitespace can be parsed using custom word
+    /// delimiters (see `with_delimiters()`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("1.0 2.5 -3.0 x");
+    ///
+    /// assert_eq!(reader.parse_array::<f32, 3>(), Ok([1.0, 2.5, -3.0]));
+    /// assert!(reader.parse_array::<f32, 1>().is_err());
+    /// assert_eq!(reader.as_str(), " x");
+    /// ```
+    pub fn parse_array<T, const N: usize>(&mut self) -> Result<[T; N], T::Err>
+    where
+        T: FromStr,
+    {
+        let start = self.checkpoint();
+
+        let mut err = None;
+
+        let values: [Option<T>; N] = core::array::from_fn(|_| {
+            if err.is_some() {
+                return None;
+            }
+
+            match self.parse_word() {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    err = Some(e);
+
+                    None
+                }
+            }
+        });
+
+        if let Some(err) = err {
+            self.rewind(start);
+
+            return Err(err);
+        }
+
+        // All values are present if there was no error.
+        Ok(values.map(|value| value.unwrap()))
+    }
+
+    /// Read the next word and parse it as an integer in a given radix. The
+    /// input won't be advanced if the word cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `radix` - radix in range from 2 to 36
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the radix is not in range from 2 to 36.
+    pub fn parse_int_radix<T>(&mut self, radix: u32) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        let (word, rest) = self.first_word();
+
+        let parsed =
+            T::from_str_radix(word, radix).map_err(|err| self.int_error(self.word_start(), err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read the next word and parse it as an integer literal. The literal may
+    /// start with an optional sign followed by an optional radix prefix (`0x`
+    /// for hexadecimal, `0o` for octal and `0b` for binary numbers). Decimal
+    /// radix is used if there is no prefix. The input won't be advanced if the
+    /// word cannot be parsed.
+    pub fn read_int_auto<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        let (word, rest) = self.first_word();
+
+        let parsed =
+            num::parse_int_auto(word).map_err(|err| self.int_error(self.word_start(), err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read a decimal integer starting at the current position. The method
+    /// consumes an optional sign and the longest sequence of decimal digits
+    /// that follows. Unlike `parse_word()`, the integer does not need to be
+    /// followed by a whitespace character or the end of the input. The input
+    /// won't be advanced if the integer cannot be parsed.
+    pub fn read_int_inline<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        let input = self.input.as_str();
+
+        let sign_len = if input.starts_with(&['+', '-'][..]) {
+            1
+        } else {
+            0
+        };
+
+        let digits_len = input[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign_len);
+
+        let (literal, rest) = input.split_at(sign_len + digits_len);
+
+        let parsed = T::from_str_radix(literal, 10).map_err(|err| self.int_error(input, err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read a floating point number starting at the current position. The
+    /// method consumes the longest sequence of characters that forms a
+    /// decimal floating point literal (i.e. an optional sign, integer part,
+    /// fractional part and an exponent). Unlike `parse_word()`, the number does
+    /// not need to be followed by a whitespace character or the end of the
+    /// input. The input won't be advanced if the number cannot be parsed.
+    pub fn read_float_inline<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableFloat,
+    {
+        let input = self.input.as_str();
+
+        let (literal, rest) = input.split_at(num::float_literal_len(input, &NumberOptions::new()));
+
+        let parsed = T::from_str(literal).map_err(|err| self.float_error(input, err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read a decimal integer starting at the current position using given
+    /// options. The method consumes the longest sequence of characters that
+    /// forms an integer literal according to the options. The input won't be
+    /// advanced if the integer cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - number format options
+    pub fn read_int_with<T>(&mut self, options: &NumberOptions) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        let input = self.input.as_str();
+
+        let (literal, rest) = input.split_at(num::int_literal_len(input, options));
+
+        let parsed = num::parse_int(literal, options).map_err(|err| self.int_error(input, err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read a floating point number starting at the current position using
+    /// given options. The method consumes the longest sequence of characters
+    /// that forms a floating point literal according to the options. The input
+    /// won't be advanced if the number cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - number format options
+    pub fn read_float_with<T>(&mut self, options: &NumberOptions) -> Result<T, ParseError>
+    where
+        T: ReadableFloat,
+    {
+        let input = self.input.as_str();
+
+        let (literal, rest) = input.split_at(num::float_literal_len(input, options));
+
+        let parsed =
+            num::parse_float(literal, options).map_err(|err| self.float_error(input, err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read a single digit in a given radix and return its value. An error
+    /// is returned if the current character is not a digit in the radix.
+    ///
+    /// # Arguments
+    ///
+    /// * `radix` - radix (from 2 to 36)
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the radix is greater than 36.
+    pub fn read_digit(&mut self, radix: u32) -> Result<u32, ParseError> {
+        let current = self.current_char();
+
+        if let Some(digit) = current.and_then(|c| c.to_digit(radix)) {
+            self.skip_char();
+
+            return Ok(digit);
+        }
+
+        let kind = if current.is_some() {
+            ErrorKind::NoMatch
+        } else {
+            ErrorKind::EmptyInput
+        };
+
+        let err = self
+            .error(kind)
+            .with_expected(Expected::Description("digit"))
+            .with_found(current);
+
+        self.track(Err(err))
+    }
+
+    /// Read the longest sequence of digits in a given radix starting at the
+    /// current position. The returned string may be empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `radix` - radix (from 2 to 36)
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the radix is greater than 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("0x1Fu8");
+    ///
+    /// reader.match_str("0x").unwrap();
+    ///
+    /// assert_eq!(reader.read_digits(16), "1F");
+    /// assert_eq!(reader.as_str(), "u8");
+    /// ```
+    #[inline]
+    pub fn read_digits(&mut self, radix: u32) -> &'a str {
+        self.read_while(|c| c.is_digit(radix))
+    }
+
+    /// Read the next word and parse it as a decimal integer of a given type.
+    /// The input won't be advanced if the word cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("80 -1.5");
+    ///
+    /// assert_eq!(reader.read_int::<u16>(), Ok(80));
+    /// assert_eq!(reader.read_float::<f32>(), Ok(-1.5));
+    /// ```
+    #[inline]
+    pub fn read_int<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableInt,
+    {
+        self.parse_int_radix(10)
+    }
+
+    /// Read the next word and parse it as a floating point number of a given
+    /// type. The input won't be advanced if the word cannot be parsed.
+    pub fn read_float<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: ReadableFloat,
+    {
+        let (word, rest) = self.first_word();
+
+        let parsed = T::from_str(word).map_err(|err| self.float_error(self.word_start(), err))?;
+
+        self.advance_to(rest);
+
+        Ok(parsed)
+    }
+
+    /// Read the next word, parse it as a decimal integer of a given type and
+    /// check that it belongs to a given range. An error is returned if the
+    /// word cannot be parsed or if the value is out of the range. The input
+    /// won't be advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - range of valid values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{ErrorKind, StringReader};
+    ///
+    /// let mut reader = StringReader::new("8080 0 x");
+    ///
+    /// assert_eq!(reader.read_int_in_range::<u16, _>(1..), Ok(8080));
+    ///
+    /// let err = reader.read_int_in_range::<u16, _>(1..).unwrap_err();
+    ///
+    /// assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid value at 1:6: expected integer in range, found '0'"
+    /// );
+    /// ```
+    pub fn read_int_in_range<T, R>(&mut self, range: R) -> Result<T, ParseError>
+    where
+        T: ReadableInt + PartialOrd,
+        R: RangeBounds<T>,
+    {
+        let word = self.word_start();
+
+        let position = self.position_of(word);
+        let found = word.chars().next();
+
+        let start = self.checkpoint();
+
+        let value = self.read_int::<T>()?;
+
+        if range.contains(&value) {
+            return Ok(value);
+        }
+
+        self.rewind(start);
+
+        let err = ParseError::new(ErrorKind::InvalidValue, position)
+            .with_expected(Expected::Description("integer in range"))
+            .with_found(found);
+
+        Err(err)
+    }
+
+    /// Read a decimal integer as i8.
+    #[inline]
+    pub fn read_i8(&mut self) -> Result<i8, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as u8.
+    #[inline]
+    pub fn read_u8(&mut self) -> Result<u8, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as i16.
+    #[inline]
+    pub fn read_i16(&mut self) -> Result<i16, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as u16.
+    #[inline]
+    pub fn read_u16(&mut self) -> Result<u16, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as i32.
+    #[inline]
+    pub fn read_i32(&mut self) -> Result<i32, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as u32.
+    #[inline]
+    pub fn read_u32(&mut self) -> Result<u32, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as i64.
+    #[inline]
+    pub fn read_i64(&mut self) -> Result<i64, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as u64.
+    #[inline]
+    pub fn read_u64(&mut self) -> Result<u64, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as i128.
+    #[inline]
+    pub fn read_i128(&mut self) -> Result<i128, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as u128.
+    #[inline]
+    pub fn read_u128(&mut self) -> Result<u128, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as isize.
+    #[inline]
+    pub fn read_isize(&mut self) -> Result<isize, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a decimal integer as usize.
+    #[inline]
+    pub fn read_usize(&mut self) -> Result<usize, ParseError> {
+        self.read_int()
+    }
+
+    /// Read a floating point number as f32.
+    #[inline]
+    pub fn read_f32(&mut self) -> Result<f32, ParseError> {
+        self.read_float()
+    }
+
+    /// Read a floating point number as f64.
+    #[inline]
+    pub fn read_f64(&mut self) -> Result<f64, ParseError> {
+        self.read_float()
+    }
+
+    /// Check if the reader is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.current_char().is_none()
+    }
+
+    /// Get the rest of the input.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.input.as_str()
+    }
+
+    /// Read the rest of the input and return it. Unlike `as_str()`, the
+    /// reader will be empty after this operation.
+    #[inline]
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.input.as_str();
+
+        self.split_to(rest.len())
+    }
+
+    /// Get the number of bytes consumed since the reader was created.
+    ///
+    /// Unlike `Position::offset`, the value is always relative to the
+    /// beginning of the input of this reader (e.g. a line reader created by
+    /// `StringReader::lines()`).
+    #[inline]
+    pub fn consumed_bytes(&self) -> usize {
+        self.origin.len() - self.input.as_str().len()
+    }
+
+    /// Get the number of characters consumed since the reader was created.
+    ///
+    /// Note that the characters are counted each time the method is called.
+    #[inline]
+    pub fn consumed_chars(&self) -> usize {
+        self.origin[..self.consumed_bytes()].chars().count()
+    }
+
+    /// Get the number of remaining bytes.
+    #[inline]
+    pub fn remaining_bytes(&self) -> usize {
+        self.input.as_str().len()
+    }
+
+    /// Get the number of remaining characters.
+    ///
+    /// Note that the characters are counted each time the method is called.
+    #[inline]
+    pub fn remaining_chars(&self) -> usize {
+        self.input.as_str().chars().count()
+    }
+
+    /// Get the first word and the remainder of the input without advancing the
+    /// input.
+    fn first_word(&self) -> (&'a str, &'a str) {
+        let input = self.word_start();
+
+        let index = self.delimiters.find(input).unwrap_or(input.len());
+
+        input.split_at(index)
+    }
+
+    /// Create an error for an integer that cannot be parsed. The integer is
+    /// expected at the beginning of a given suffix of the remaining input.
+    fn int_error(&self, input: &str, err: ParseIntError) -> ParseError {
+        self.number_error(input, "integer", (&err).into())
+    }
+
+    /// Create an error for a floating point number that cannot be parsed.
+    /// The number is expected at the beginning of a given suffix of the
+    /// remaining input.
+    fn float_error(&self, input: &str, err: ParseFloatError) -> ParseError {
+        self.number_error(input, "floating point number", (&err).into())
+    }
+
+    /// Create an error for a number that cannot be parsed. `EmptyInput` is
+    /// reported if there is nothing left in the input.
+    fn number_error(&self, input: &str, expected: &'static str, err: NumberError) -> ParseError {
+        let kind = if input.is_empty() {
+            ErrorKind::EmptyInput
+        } else {
+            ErrorKind::InvalidNumber
+        };
+
+        ParseError::new(kind, self.position_of(input))
+            .with_expected(Expected::Description(expected))
+            .with_found(input.chars().next())
+            .with_number_error(err)
+    }
+
+    /// Match the longest of given alternatives to the input and return its
+    /// index. See `match_longest()` for more info.
+    fn longest_match<K>(&mut self, table: &[(&str, K)]) -> Result<usize, ParseError> {
+        let input = self.input.as_str();
+
+        let mut best: Option<(usize, &str)> = None;
+
+        for (index, (val, _)) in table.iter().enumerate() {
+            let len = best.map_or(0, |(_, best)| best.len());
+
+            if val.len() > len && input.starts_with(val) {
+                best = Some((index, val));
+            }
+        }
+
+        if let Some((index, val)) = best {
+            self.split_to(val.len());
+
+            return Ok(index);
+        }
+
+        let kind = if self.is_empty() {
+            ErrorKind::EmptyInput
+        } else {
+            ErrorKind::NoMatch
+        };
+
+        for (val, _) in table.iter().filter(|(val, _)| !val.is_empty()) {
+            let err = self
+                .error(kind)
+                .with_expected(Expected::Str(InlineStr::new(val)))
+                .with_found(self.current_char());
+
+            let _ = self.track::<()>(Err(err));
+        }
+
+        let err = self
+            .error(kind)
+            .with_expected(Expected::Description("one of the alternatives"))
+            .with_found(self.current_char());
+
+        Err(err)
+    }
+
+    /// Get the remaining input starting at the beginning of the next word.
+    /// Delimiters and comments are skipped unless the strict mode is enabled
+    /// (see `with_strict_words()`).
+    fn word_start(&self) -> &'a str {
+        let input = self.input.as_str();
+
+        if self.strict_words {
+            input
+        } else {
+            self.trim_start(input)
+        }
+    }
+
+    /// Split the input at a given index and return the first part.
+    ///
+    /// The input will contain the remaining part after this operation.
+    fn split_to(&mut self, index: usize) -> &'a str {
+        let rest = self.input.as_str();
+
+        let (word, rest) = rest.split_at(index);
+
+        self.advance_to(rest);
+
+        word
+    }
+
+    /// Create a new reader for a given part of the input starting at a given
+    /// position. The new reader will inherit settings of this reader.
+    fn sub_reader(&self, input: &'a str, position: Position) -> Self {
+        let origin = input;
+        let input = origin.chars();
+        let current = input.clone().next();
+
+        Self {
+            origin,
+            input,
+            current,
+            position,
+            delimiters: self.delimiters,
+            comments: self.comments,
+            strict_words: self.strict_words,
+            tracked: Cell::new(self.tracked.get().map(|_| ExpectedSet::new())),
+        }
+    }
+
+    /// Get position of the end of the input.
+    fn end_position(&self) -> Position {
+        let mut position = self.position;
+
+        position.advance_str(self.input.as_str());
+
+        position
+    }
+
+    /// Get position of a given remainder of the input.
+    ///
+    /// The remainder must be a suffix of the current input.
+    fn position_of(&self, rest: &str) -> Position {
+        let input = self.input.as_str();
+
+        let mut position = self.position;
+
+        position.advance_str(&input[..input.len() - rest.len()]);
+
+        position
+    }
+
+    /// Get span of a given word followed by a given remainder of the input.
+    ///
+    /// The word and the remainder must form a suffix of the current input.
+    fn word_span(&self, word: &str, rest: &str) -> Span {
+        let input = self.input.as_str();
+
+        let start = self.position_of(&input[input.len() - rest.len() - word.len()..]);
+
+        let mut end = start;
+
+        end.advance_str(word);
+
+        Span { start, end }
+    }
+
+    /// Create an error of a given kind at the current position.
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError::new(kind, self.position)
+    }
+
+    /// Match a given character to the input using a given comparison
+    /// function.
+    fn match_char_by<F>(&mut self, expected: char, eq: F) -> Result<(), ParseError>
+    where
+        F: FnOnce(char, char) -> bool,
+    {
+        let c = match self.current_char() {
+            Some(c) => c,
+            None => {
+                let err = self
+                    .error(ErrorKind::EmptyInput)
+                    .with_expected(Expected::Char(expected));
+
+                return self.track(Err(err));
+            }
+        };
+
+        if !eq(c, expected) {
+            let err = self
+                .error(ErrorKind::NoMatch)
+                .with_expected(Expected::Char(expected))
+                .with_found(Some(c));
+
+            return self.track(Err(err));
+        }
+
+        self.skip_char();
+
+        Ok(())
+    }
+
+    /// Match a given string to the input character by character using a given
+    /// comparison function. In case of a failure, the error will point to the
+    /// first character that does not match.
+    fn match_str_by<F>(&mut self, val: &str, mut eq: F) -> Result<(), ParseError>
+    where
+        F: FnMut(char, char) -> bool,
+    {
+        let mut input = self.input.clone();
+        let mut position = self.position;
+
+        for e in val.chars() {
+            match input.next() {
+                Some(c) if eq(c, e) => position.advance_char(c),
+                c => {
+                    let kind = if c.is_some() {
+                        ErrorKind::NoMatch
+                    } else {
+                        ErrorKind::EmptyInput
+                    };
+
+                    let err = ParseError::new(kind, position)
+                        .with_expected(Expected::Str(InlineStr::new(val)))
+                        .with_found(c);
+
+                    return self.track(Err(err));
+                }
+            }
+        }
+
+        self.input = input;
+        self.current = self.input.clone().next();
+        self.position = position;
+
+        Ok(())
+    }
+
+    /// Advance the input to a given remainder.
+    ///
+    /// The remainder must be a suffix of the current input.
+    fn advance_to(&mut self, rest: &'a str) {
+        let input = self.input.as_str();
+
+        self.position
+            .advance_str(&input[..input.len() - rest.len()]);
+
+        self.input = rest.chars();
+
+        // Peek for the next character without advancing the input.
+        self.current = self.input.clone().next();
+    }
+}
+
+impl Debug for StringReader<'_> {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        let mut res = f.debug_struct("StringReader");
+
+        res.field("position", &format_args!("{}", self.position));
+
+        if let Some(c) = self.current {
+            res.field("next", &c);
+        } else {
+            res.field("next", &format_args!("None"));
+        }
+
+        res.field("rest", &Preview(self.input.as_str())).finish()
+    }
+}
+
+/// Debug helper displaying the beginning of a given string.
+struct Preview<'a>(&'a str);
+
+impl Preview<'_> {
+    /// Maximum number of characters displayed.
+    const LEN: usize = 16;
+}
+
+impl Debug for Preview<'_> {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        let end = self
+            .0
+            .char_indices()
+            .nth(Self::LEN)
+            .map_or(self.0.len(), |(index, _)| index);
+
+        write!(f, "\"{}", self.0[..end].escape_debug())?;
+
+        if end < self.0.len() {
+            f.write_str("\u{2026}")?;
+        }
+
+        f.write_str("\"")
+    }
+}
+
+/// Find the byte index of the first occurrence of a given character.
+#[cfg(feature = "memchr")]
+fn find_char(s: &str, c: char) -> Option<usize> {
+    if c.is_ascii() {
+        memchr::memchr(c as u8, s.as_bytes())
+    } else {
+        let mut buf = [0u8; 4];
+
+        memchr::memmem::find(s.as_bytes(), c.encode_utf8(&mut buf).as_bytes())
+    }
+}
+
+/// Find the byte index of the first occurrence of a given character.
+#[cfg(not(feature = "memchr"))]
+#[inline]
+fn find_char(s: &str, c: char) -> Option<usize> {
+    s.find(c)
+}
+
+/// Compare two characters using their Unicode lowercase mapping.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::IntErrorKind;
+
+    use super::{
+        Delimiters, ErrorKind, Expected, FloatErrorKind, NumberError, NumberOptions, Overflow,
+        ParseError, Position, StringReader,
+    };
+
+    #[test]
+    fn test_reader() {
+        let input = "Hello, World!!!   1234\n\tfoo-bar";
+
+        let mut reader = StringReader::new(input);
+
+        assert!(!reader.is_empty());
+        assert_eq!(reader.current_char(), Some('H'));
+        assert_eq!(reader.as_str(), input);
+
+        let word = reader.read_word();
+
+        assert_eq!(word, "Hello,");
+        assert_eq!(reader.as_str(), " World!!!   1234\n\tfoo-bar");
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.as_str(), "World!!!   1234\n\tfoo-bar");
+
+        let c = reader.read_char();
+
+        assert_eq!(c.ok(), Some('W'));
+
+        let res = reader.match_char('o');
+
+        assert!(res.is_ok());
+
+        let res = reader.match_char('R');
+
+        assert!(res.is_err());
+
+        let res = reader.match_str("RLD!!!");
+
+        assert!(res.is_err());
+
+        let res = reader.match_str("rld!!!");
+
+        assert!(res.is_ok());
+        assert_eq!(reader.as_str(), "   1234\n\tfoo-bar");
+
+        let n = reader.read_u32();
+
+        assert_eq!(n.ok(), Some(1234));
+        assert_eq!(reader.as_str(), "\n\tfoo-bar");
+
+        let n = reader.read_u32();
+
+        assert!(n.is_err());
+        assert_eq!(reader.as_str(), "\n\tfoo-bar");
+
+        let word = reader.read_word();
+
+        assert_eq!(word, "foo-bar");
+        assert_eq!(reader.as_str(), "");
+        assert!(reader.is_empty());
+
+        let word = reader.read_word();
+
+        assert_eq!(word, "");
+
+        let c = reader.read_char();
+
+        assert!(c.is_err());
+        assert!(reader.is_empty());
+        assert_eq!(reader.as_str(), "");
+    }
+
+    #[test]
+    fn test_position() {
+        let mut reader = StringReader::new("ab\nčd  ef\r\n\n gh");
+
+        assert_eq!(
+            reader.position(),
+            Position {
+                line: 1,
+                column: 1,
+                offset: 0
+            }
+        );
+
+        reader.read_char().unwrap();
+        reader.read_word();
+
+        assert_eq!(
+            reader.position(),
+            Position {
+                line: 1,
+                column: 3,
+                offset: 2
+            }
+        );
+
+        reader.skip_char();
+        reader.match_str("čd").unwrap();
+
+        assert_eq!(
+            reader.position(),
+            Position {
+                line: 2,
+                column: 3,
+                offset: 6
+            }
+        );
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(
+            reader.position(),
+            Position {
+                line: 4,
+                column: 2,
+                offset: 14
+            }
+        );
+
+        reader.read_until(|_| false);
+
+        assert_eq!(reader.position().offset, 16);
+        assert_eq!(reader.position().column, 4);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_error_context() {
+        let mut reader = StringReader::new("HTTX/1.1");
+
+        let err = reader.match_str("HTTP/").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.position().offset, 3);
+        assert_eq!(err.found(), Some('X'));
+
+        match err.expected() {
+            Some(Expected::Str(s)) => assert_eq!(s.as_str(), "HTTP/"),
+            _ => panic!("unexpected expectation"),
+        }
+
+        assert_eq!(
+            err.to_string(),
+            "the input does not match at 1:4: expected \"HTTP/\", found 'X'"
+        );
+
+        let err = reader.match_str("HTTX/1.1 200").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, 8);
+        assert_eq!(err.found(), None);
+        assert_eq!(reader.as_str(), "HTTX/1.1");
+
+        let err = reader.match_str_ignore_case("httx/1.1 200").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+
+        let err = reader.match_char('h').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.expected(), Some(&Expected::Char('h')));
+        assert_eq!(err.found(), Some('H'));
+
+        reader.read_word();
+
+        let err = reader.match_char('h').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, 8);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let mut reader = StringReader::new("foo\nbar baz");
+
+        reader.read_word();
+
+        let checkpoint = reader.checkpoint();
+
+        reader.read_word();
+        reader.read_word();
+
+        assert!(reader.is_empty());
+
+        reader.rewind(checkpoint);
+
+        assert_eq!(reader.position(), checkpoint.position());
+        assert_eq!(reader.current_char(), Some('\n'));
+        assert_eq!(reader.as_str(), "\nbar baz");
+        assert_eq!(reader.read_word(), "bar");
+        assert_eq!(reader.position().line, 2);
+
+        let mut reader = StringReader::new("key = value;");
+
+        let start = reader.checkpoint();
+
+        reader.read_word();
+        reader.match_str(" = ").unwrap();
+        reader.read_until_char(';');
+
+        assert_eq!(reader.slice(start, reader.checkpoint()), "key = value");
+        assert_eq!(reader.slice(start, start), "");
+    }
+
+    #[test]
+    fn test_capture() {
+        let mut reader = StringReader::new("a\nbc d");
+
+        reader.read_char().unwrap();
+
+        let capture = reader.start_capture();
+
+        assert_eq!(capture.finish(&reader), "");
+
+        reader.skip_whitespace();
+        reader.read_word();
+
+        assert_eq!(capture.position().column, 2);
+        assert_eq!(capture.finish(&reader), "\nbc");
+
+        reader.read_word();
+
+        assert_eq!(capture.finish(&reader), "\nbc d");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_order() {
+        let mut reader = StringReader::new("foo bar");
+
+        let start = reader.checkpoint();
+
+        reader.read_word();
+
+        reader.slice(reader.checkpoint(), start);
+    }
+
+    #[test]
+    fn test_transaction() {
+        let mut reader = StringReader::new("let x = 10;");
+
+        let res = reader.transaction(|r| {
+            r.match_str("let")?;
+            r.skip_whitespace();
+
+            let name = r.read_until(|c| c.is_whitespace());
+
+            r.transaction(|r| {
+                r.skip_whitespace();
+                r.match_str("==")
+            })
+            .unwrap_err();
+
+            assert_eq!(r.as_str(), " = 10;");
+
+            r.skip_whitespace();
+            r.match_char(':')?;
+
+            Ok::<_, ParseError>(name)
+        });
+
+        assert!(res.is_err());
+        assert_eq!(reader.as_str(), "let x = 10;");
+        assert_eq!(reader.position().offset, 0);
+
+        let res = reader.transaction(|r| {
+            r.match_str("let")?;
+            r.read_word();
+            r.skip_whitespace();
+            r.match_char('=')
+        });
+
+        assert!(res.is_ok());
+        assert_eq!(reader.as_str(), " 10;");
+    }
+
+    #[test]
+    fn test_lookahead() {
+        let reader = StringReader::new("<=ěš");
+
+        assert_eq!(reader.peek_str(0), "");
+        assert_eq!(reader.peek_str(1), "<");
+        assert_eq!(reader.peek_str(3), "<=ě");
+        assert_eq!(reader.peek_str(10), "<=ěš");
+
+        assert!(reader.starts_with("<="));
+        assert!(reader.starts_with(""));
+        assert!(!reader.starts_with("<<"));
+
+        assert_eq!(reader.as_str(), "<=ěš");
+
+        let mut reader = StringReader::new("  GET /index.html");
+
+        assert_eq!(reader.peek_word(), "GET");
+        assert_eq!(reader.as_str(), "  GET /index.html");
+        assert_eq!(reader.read_word(), "GET");
+        assert_eq!(reader.peek_word(), "/index.html");
+
+        reader.read_word();
+
+        assert_eq!(reader.peek_word(), "");
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        let mut reader = StringReader::new("Content-TYPE: ŽLUŤOUČKÝ");
+
+        assert!(reader.match_char_ignore_ascii_case('C').is_ok());
+        assert!(reader.match_char_ignore_ascii_case('O').is_ok());
+        assert!(reader.match_str_ignore_ascii_case("ntent-type:").is_ok());
+
+        reader.skip_whitespace();
+
+        let err = reader.match_str_ignore_ascii_case("žluť").unwrap_err();
+
+        assert_eq!(err.found(), Some('Ž'));
+        assert_eq!(err.position().offset, 14);
+
+        assert!(reader.match_char_ignore_case('ž').is_ok());
+        assert!(reader.match_str_ignore_case("luťoučký").is_ok());
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_radix() {
+        let mut reader = StringReader::new("ff 0777 -101 zz");
+
+        assert_eq!(reader.parse_int_radix::<u8>(16), Ok(255));
+        assert_eq!(reader.parse_int_radix::<u16>(8), Ok(511));
+        assert_eq!(reader.parse_int_radix::<i32>(2), Ok(-5));
+        assert!(reader.parse_int_radix::<u64>(16).is_err());
+        assert_eq!(reader.as_str(), " zz");
+        assert_eq!(reader.parse_int_radix::<u64>(36), Ok(35 * 36 + 35));
+    }
+
+    #[test]
+    fn test_generic_numbers() {
+        let mut reader = StringReader::new("255 256 -7 1e3 x");
+
+        assert_eq!(reader.read_int::<u8>(), Ok(255));
+        assert!(reader.read_int::<u8>().is_err());
+        assert_eq!(reader.read_int::<u16>(), Ok(256));
+        assert_eq!(reader.read_int::<i128>(), Ok(-7));
+        assert!(reader.read_int::<u64>().is_err());
+        assert_eq!(reader.read_float::<f64>(), Ok(1000.0));
+        assert!(reader.read_float::<f32>().is_err());
+        assert_eq!(reader.as_str(), " x");
+    }
+
+    #[test]
+    fn test_int_auto() {
+        let mut reader = StringReader::new("0xff -0x80 +0o17 0b101 -12 0x 0b2");
+
+        assert_eq!(reader.read_int_auto::<u8>(), Ok(255));
+        assert_eq!(reader.read_int_auto::<i8>(), Ok(-128));
+        assert_eq!(reader.read_int_auto::<u32>(), Ok(15));
+        assert_eq!(reader.read_int_auto::<u32>(), Ok(5));
+        assert!(reader.read_int_auto::<u32>().is_err());
+        assert_eq!(reader.read_int_auto::<i32>(), Ok(-12));
+
+        let err = reader.read_int_auto::<u32>().unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::Empty))
+        );
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.position().column, 28);
+
+        reader.read_word();
+
+        let err = reader.read_int_auto::<u32>().unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::InvalidDigit))
+        );
+        assert_eq!(reader.as_str(), " 0b2");
+
+        let mut reader = StringReader::new("0x100 -0x81");
+
+        let err = reader.read_int_auto::<u8>().unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::PosOverflow))
+        );
+        assert_eq!(reader.read_int_auto::<u16>(), Ok(256));
+
+        let err = reader.read_int_auto::<i8>().unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::NegOverflow))
+        );
+    }
+
+    #[test]
+    fn test_int_inline() {
+        let mut reader = StringReader::new("123abc-42,+7 8");
+
+        assert_eq!(reader.read_int_inline::<u32>(), Ok(123));
+        assert_eq!(reader.as_str(), "abc-42,+7 8");
+        assert!(reader.read_int_inline::<u32>().is_err());
+
+        reader.match_str("abc").unwrap();
+
+        assert!(reader.read_int_inline::<u32>().is_err());
+        assert_eq!(reader.read_int_inline::<i32>(), Ok(-42));
+        assert!(reader.match_char(',').is_ok());
+        assert_eq!(reader.read_int_inline::<u8>(), Ok(7));
+        assert!(reader.read_int_inline::<u8>().is_err());
+        assert_eq!(reader.as_str(), " 8");
+    }
+
+    #[test]
+    fn test_float_inline() {
+        let mut reader = StringReader::new("3.5+2e3*-.25e-1/1.e+x.");
+
+        assert_eq!(reader.read_float_inline::<f64>(), Ok(3.5));
+        assert_eq!(reader.read_float_inline::<f64>(), Ok(2000.0));
+        assert!(reader.match_char('*').is_ok());
+        assert_eq!(reader.read_float_inline::<f32>(), Ok(-0.025));
+        assert!(reader.read_float_inline::<f32>().is_err());
+        assert!(reader.match_char('/').is_ok());
+        assert_eq!(reader.read_float_inline::<f64>(), Ok(1.0));
+        assert_eq!(reader.as_str(), "e+x.");
+
+        reader.match_str("e+x").unwrap();
+
+        assert!(reader.read_float_inline::<f64>().is_err());
+        assert_eq!(reader.as_str(), ".");
+    }
+
+    #[test]
+    fn test_digit_separator() {
+        let options = NumberOptions::new().digit_separator('_');
+
+        let mut reader = StringReader::new("1_000_000 -1__2_ _3 1_0.2_5e1_0 1_.5");
+
+        assert_eq!(reader.read_int_with::<u32>(&options), Ok(1_000_000));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<i32>(&options), Ok(-12));
+        assert_eq!(reader.as_str(), "_ _3 1_0.2_5e1_0 1_.5");
+
+        reader.skip_char();
+        reader.skip_whitespace();
+
+        assert!(reader.read_int_with::<i32>(&options).is_err());
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_int_with::<u8>(&NumberOptions::new()), Ok(3));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(10.25e10));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(1.0));
+        assert_eq!(reader.as_str(), "_.5");
+    }
+
+    #[test]
+    fn test_decimal_separator() {
+        let options = NumberOptions::new()
+            .decimal_separator(',')
+            .digit_separator(' ');
+
+        let mut reader = StringReader::new("3,25;1 234,5;2.5;,5e1");
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(3.25));
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(1234.5));
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(2.0));
+        assert_eq!(reader.as_str(), ".5;,5e1");
+
+        reader.match_str(".5;").unwrap();
+
+        assert_eq!(reader.read_float_with::<f32>(&options), Ok(5.0));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_group_separator() {
+        let options = NumberOptions::new().group_separator(',');
+
+        let mut reader = StringReader::new("1,234,567 12,34 1234,567 -1,000,0000 ,1");
+
+        assert_eq!(reader.read_int_with::<u32>(&options), Ok(1_234_567));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<u32>(&options), Ok(12));
+        assert_eq!(reader.as_str(), ",34 1234,567 -1,000,0000 ,1");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<u32>(&options), Ok(1234));
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<i32>(&options), Ok(-1000));
+        assert_eq!(reader.as_str(), ",0000 ,1");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.read_int_with::<u32>(&options).is_err());
+
+        let options = NumberOptions::new()
+            .group_separator('.')
+            .decimal_separator(',');
+
+        let mut reader = StringReader::new("1.234.567,25 1'000.5");
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(1234567.25));
+
+        reader.skip_whitespace();
+
+        let options = NumberOptions::new().group_separator('\'');
+
+        assert_eq!(reader.read_float_with::<f64>(&options), Ok(1000.5));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let saturate = NumberOptions::new().overflow(Overflow::Saturate);
+        let wrap = NumberOptions::new().overflow(Overflow::Wrap);
+
+        let mut reader = StringReader::new("300 300 -129 -129 -5 -5 999x");
+
+        assert_eq!(reader.read_int_with::<u8>(&saturate), Ok(255));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<u8>(&wrap), Ok(44));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<i8>(&saturate), Ok(-128));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<i8>(&wrap), Ok(127));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<u8>(&saturate), Ok(0));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_int_with::<u8>(&wrap), Ok(251));
+
+        reader.skip_whitespace();
+
+        let err = reader
+            .read_int_with::<u8>(&NumberOptions::new())
+            .unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::PosOverflow))
+        );
+        assert_eq!(reader.read_int_with::<u16>(&saturate), Ok(999));
+    }
+
+    #[test]
+    fn test_sign_options() {
+        let strict = NumberOptions::new().plus_sign(false);
+        let relaxed = NumberOptions::new().sign_whitespace(true);
+
+        let mut reader = StringReader::new("+10 -\t2 - x + 1.5e+1 -2");
+
+        assert!(reader.read_int_with::<u32>(&strict).is_err());
+        assert_eq!(reader.read_int_with::<u32>(&relaxed), Ok(10));
+
+        reader.skip_whitespace();
+
+        assert!(reader.read_int_with::<i32>(&NumberOptions::new()).is_err());
+        assert_eq!(reader.read_int_with::<i32>(&relaxed), Ok(-2));
+
+        reader.skip_whitespace();
+
+        assert!(reader.read_int_with::<i32>(&relaxed).is_err());
+        assert_eq!(reader.as_str(), "- x + 1.5e+1 -2");
+
+        reader.match_str("- x ").unwrap();
+
+        assert!(reader.read_float_with::<f64>(&strict).is_err());
+        assert_eq!(reader.read_float_with::<f64>(&relaxed), Ok(15.0));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_float_with::<f32>(&strict), Ok(-2.0));
+    }
+
+    #[test]
+    fn test_balanced() {
+        let mut reader = StringReader::new("(a (b) c) d [x [y] z");
+
+        assert_eq!(reader.read_balanced('(', ')'), Ok("a (b) c"));
+        assert_eq!(reader.as_str(), " d [x [y] z");
+        assert!(reader.read_balanced('(', ')').is_err());
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_balanced('[', ']').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.expected(), Some(&Expected::Char(']')));
+        assert_eq!(err.position().offset, 20);
+        assert_eq!(reader.as_str(), "[x [y] z");
+
+        let mut reader = StringReader::new("||");
+
+        assert_eq!(reader.read_balanced('|', '|'), Ok(""));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_consumed() {
+        let mut reader = StringReader::new("čau\nsvěte");
+
+        assert_eq!(reader.consumed_bytes(), 0);
+        assert_eq!(reader.remaining_chars(), 9);
+
+        reader.read_line();
+
+        assert_eq!(reader.consumed_bytes(), 5);
+        assert_eq!(reader.consumed_chars(), 4);
+        assert_eq!(reader.remaining_bytes(), 6);
+        assert_eq!(reader.remaining_chars(), 5);
+
+        let mut reader = StringReader::new("a\nbc d");
+
+        let mut line = reader.lines().nth(1).unwrap();
+
+        line.read_word();
+
+        assert_eq!(line.position().offset, 4);
+        assert_eq!(line.consumed_bytes(), 2);
+        assert_eq!(line.remaining_bytes(), 2);
+    }
+
+    #[test]
+    fn test_spanned() {
+        let input = "  foo\n 42 x";
+
+        let mut reader = StringReader::new(input);
+
+        let (word, span) = reader.read_word_spanned();
+
+        assert_eq!(word, "foo");
+        assert_eq!(&input[span.range()], "foo");
+        assert_eq!(span.start.column, 3);
+
+        let (n, span) = reader.parse_word_spanned::<u32>().unwrap();
+
+        assert_eq!(n, 42);
+        assert_eq!(span.range(), 7..9);
+        assert_eq!(span.start.line, 2);
+        assert_eq!(span.end.column, 4);
+
+        assert!(reader.parse_word_spanned::<u32>().is_err());
+        assert_eq!(reader.as_str(), " x");
+
+        let (c, span) = reader
+            .spanned(|r| {
+                r.skip_whitespace();
+                r.read_char()
+            })
+            .unwrap();
+
+        assert_eq!(c, 'x');
+        assert_eq!(span.range(), 9..11);
+    }
+
+    #[test]
+    fn test_read_exact() {
+        let mut reader = StringReader::new("ěšč12");
+
+        assert_eq!(reader.read_exact(0), Ok(""));
+        assert_eq!(reader.read_exact(3), Ok("ěšč"));
+
+        let err = reader.read_exact(3).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().column, 6);
+        assert_eq!(reader.as_str(), "12");
+        assert_eq!(reader.read_exact(2), Ok("12"));
+    }
+
+    #[test]
+    fn test_take() {
+        let mut reader =
+            StringReader::new("žluť 123").with_delimiters(Delimiters::Custom(|c| c == 'ť'));
+
+        let mut field = reader.take(4).unwrap();
+
+        assert_eq!(field.read_word(), "žlu");
+        assert_eq!(field.read_word(), "");
+        assert!(field.is_empty());
+
+        assert!(reader.take(5).is_err());
+        assert_eq!(reader.position().column, 5);
+
+        let err = StringReader::new("žluť").take_bytes(1).err().unwrap();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.found(), Some('ž'));
+
+        let mut reader = StringReader::new("žluť");
+
+        assert!(reader.take_bytes(7).is_err());
+
+        let field = reader.take_bytes(4).unwrap();
+
+        assert_eq!(field.as_str(), "žlu");
+        assert_eq!(field.position().column, 1);
+        assert_eq!(reader.position().column, 4);
+        assert_eq!(reader.as_str(), "ť");
+    }
+
+    #[test]
+    fn test_skip_bom() {
+        let mut reader = StringReader::new("\u{feff}BOM");
+
+        assert!(reader.skip_bom());
+        assert!(!reader.skip_bom());
+        assert_eq!(reader.position().column, 1);
+        assert_eq!(reader.position().offset, 3);
+        assert_eq!(reader.match_str("BOM"), Ok(()));
+    }
+
+    #[test]
+    fn test_read_until_ascii() {
+        let mut reader = StringReader::new("čau,x=1");
+
+        assert_eq!(reader.read_until_ascii(|b| b == b',' || b == b'='), "čau");
+        assert_eq!(reader.position().column, 4);
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_until_ascii(|b| b == b'='), "x");
+        assert_eq!(reader.read_until_ascii(|_| false), "=1");
+    }
+
+    #[test]
+    fn test_read_until_char() {
+        let mut reader = StringReader::new("key;ví=ř;x");
+
+        assert_eq!(reader.read_until_char(';'), "key");
+        assert_eq!(reader.read_char(), Ok(';'));
+        assert_eq!(reader.read_until_char('ř'), "ví=");
+        assert_eq!(reader.position().column, 8);
+        assert_eq!(reader.read_until_char('#'), "ř;x");
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_until_str() {
+        let mut reader = StringReader::new("<!-- comment --> text */ end");
+
+        reader.match_str("<!--").unwrap();
+
+        assert_eq!(reader.read_until_str("-->"), " comment ");
+        assert_eq!(reader.as_str(), "--> text */ end");
+
+        reader.match_str("-->").unwrap();
+
+        assert_eq!(reader.read_until_str_and_skip("*/"), Ok(" text "));
+        assert_eq!(reader.as_str(), " end");
+
+        let err = reader.read_until_str_and_skip("\r\n\r\n").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, 28);
+        assert_eq!(reader.as_str(), " end");
+        assert_eq!(reader.read_until_str("\r\n\r\n"), " end");
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_while() {
+        let mut reader = StringReader::new("123abc   ěšx");
+
+        assert_eq!(reader.read_while(|c| c.is_ascii_digit()), "123");
+        assert_eq!(reader.read_while(|c| c.is_ascii_digit()), "");
+        assert_eq!(reader.read_while(char::is_alphabetic), "abc");
+        assert_eq!(reader.skip_while(char::is_whitespace), 3);
+        assert_eq!(reader.skip_while(|c| c != 'x'), 2);
+        assert_eq!(reader.as_str(), "x");
+        assert_eq!(reader.position().column, 12);
+    }
+
+    #[test]
+    fn test_skip_until() {
+        let mut reader = StringReader::new("čau <body>x</body>");
+
+        assert_eq!(reader.skip_until(|c| c == '<'), 4);
+        assert_eq!(reader.skip_until(|c| c == '<'), 0);
+        assert_eq!(reader.skip_until_str("</"), 7);
+        assert_eq!(reader.as_str(), "</body>");
+        assert_eq!(reader.skip_until_str("<html>"), 7);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_delimiters() {
+        let mut reader =
+            StringReader::new(" 1:23::foo bar:").with_delimiters(Delimiters::Custom(|c| c == ':'));
+
+        assert_eq!(reader.read_word(), " 1");
+        assert_eq!(reader.read_u32(), Ok(23));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.as_str(), "foo bar:");
+        assert_eq!(reader.read_word(), "foo bar");
+        assert_eq!(reader.read_word(), "");
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_int_in_range() {
+        let mut reader = StringReader::new("100 101 -5 abc");
+
+        assert_eq!(reader.read_int_in_range::<u8, _>(0..=100), Ok(100));
+
+        let err = reader.read_int_in_range::<u8, _>(0..=100).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().offset, 4);
+        assert_eq!(err.found(), Some('1'));
+
+        assert_eq!(reader.read_int_in_range::<i32, _>(..), Ok(101));
+        assert_eq!(reader.read_int_in_range::<i8, _>(-10..0), Ok(-5));
+
+        let err = reader.read_int_in_range::<i8, _>(..).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.position().offset, 11);
+        assert_eq!(reader.read_word(), "abc");
+
+        let err = reader.read_int_in_range::<i8, _>(..).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.found(), None);
+    }
+
+    #[test]
+    fn test_take_rest() {
+        let mut reader = StringReader::new("PRIVMSG #chan :hello\nworld");
+
+        reader.read_word();
+        reader.read_word();
+        reader.skip_whitespace();
+        reader.match_char(':').unwrap();
+
+        assert_eq!(reader.take_rest(), "hello\nworld");
+        assert!(reader.is_empty());
+        assert_eq!(reader.position().line, 2);
+        assert_eq!(reader.take_rest(), "");
+    }
 
-        f.write_str(msg)
+    #[test]
+    fn test_parse_array() {
+        let mut reader = StringReader::new("1,2,3;4,x")
+            .with_delimiters(Delimiters::Custom(|c| c == ',' || c == ';'));
+
+        assert_eq!(reader.parse_array::<u8, 0>(), Ok([]));
+        assert_eq!(reader.parse_array::<u8, 2>(), Ok([1, 2]));
+        assert!(reader.parse_array::<u8, 3>().is_err());
+        assert_eq!(reader.as_str(), ",3;4,x");
+        assert_eq!(reader.parse_array::<u8, 2>(), Ok([3, 4]));
+        assert_eq!(reader.parse_array::<char, 1>(), Ok(['x']));
+        assert!(reader.parse_array::<char, 1>().is_err());
     }
-}
 
-impl Error for ParseError {}
+    #[test]
+    fn test_match_char_if() {
+        let mut reader = StringReader::new("x_7");
 
-/// String reader.
-pub struct StringReader<'a> {
-    input: Chars<'a>,
-    current: Option<char>,
-}
+        assert_eq!(reader.match_char_if(|c| c.is_ascii_lowercase()), Ok('x'));
 
-impl<'a> StringReader<'a> {
-    /// Create a new reader for a given input.
-    ///
-    /// # Arguments
-    ///
-    /// * `input` - input string or an object that can be referenced as a
-    ///   string
-    pub fn new<T>(input: &'a T) -> Self
-    where
-        T: AsRef<str> + ?Sized,
-    {
-        let input = input.as_ref().chars();
+        let err = reader.match_char_if(|c| c.is_ascii_digit()).unwrap_err();
 
-        // We do not want to advance the input just yet. If we did that the
-        // string matching methods would not work.
-        let current = input.clone().next();
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('_'));
+        assert_eq!(err.position().offset, 1);
 
-        Self { input, current }
-    }
+        assert_eq!(reader.match_char_if(|c| c == '_'), Ok('_'));
+        assert_eq!(reader.match_char_if(|c| c.is_ascii_digit()), Ok('7'));
 
-    /// Get the current character (if any) without advancing the input.
-    #[inline]
-    pub fn current_char(&self) -> Option<char> {
-        self.current
+        let err = reader.match_char_if(|_| true).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
     }
 
-    /// Get the next character or return an error if the input is empty.
-    pub fn read_char(&mut self) -> Result<char, ParseError> {
-        let res = self.input.next().ok_or(ParseError::EmptyInput)?;
+    #[test]
+    fn test_match_longest() {
+        let table = [("=", 1), ("==", 2), ("=>", 3), ("", 4), ("=", 5)];
 
-        // Peek for the next character without advancing the input.
-        self.current = self.input.clone().next();
+        let mut reader = StringReader::new("=== =>!").with_expectation_tracking();
 
-        Ok(res)
-    }
+        assert_eq!(reader.match_longest(&table), Ok(2));
+        assert_eq!(reader.match_longest(&table), Ok(1));
 
-    /// Match a given character to the input and, if successful, advance the
-    /// input by exactly one character. An error is returned if the input
-    /// character does not match with the given one or if the input is empty.
-    ///
-    /// # Arguments
-    ///
-    /// * `expected` - expected character
-    pub fn match_char(&mut self, expected: char) -> Result<(), ParseError> {
-        let c = self.current_char().ok_or(ParseError::EmptyInput)?;
+        let err = reader.match_longest(&table).unwrap_err();
 
-        if c != expected {
-            return Err(ParseError::NoMatch);
-        }
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some(' '));
+        assert_eq!(err.position().offset, 3);
+        assert_eq!(reader.expected_set().unwrap().len(), 3);
 
-        self.skip_char();
+        reader.skip_whitespace();
 
-        Ok(())
-    }
+        assert_eq!(reader.match_longest(&table), Ok(3));
+        assert!(reader.match_longest(&table).is_err());
 
-    /// Skip one character.
-    pub fn skip_char(&mut self) {
-        // Remove the current character.
-        self.input.next();
+        reader.skip_char();
 
-        // Peek for the next character without advancing the input.
-        self.current = self.input.clone().next();
+        assert_eq!(
+            reader.match_longest(&table).unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
     }
 
-    /// Skip all whitespace characters.
-    pub fn skip_whitespace(&mut self) {
-        let rest = self.input.as_str().trim_start();
+    #[test]
+    fn test_with_offset() {
+        let mut reader = StringReader::new("foo bar").with_offset(100);
 
-        self.input = rest.chars();
+        reader.read_word();
 
-        // Peek for the next character without advancing the input.
-        self.current = self.input.clone().next();
-    }
+        let (word, span) = reader.read_word_spanned();
 
-    /// Match a given string to the input and, if successful, advance the input
-    /// by the length of the given string. An error is returned if the input
-    /// does not start with the given string.
-    ///
-    /// # Arguments
-    ///
-    /// * `val` - expected string
-    pub fn match_str(&mut self, val: &str) -> Result<(), ParseError> {
-        let input = self.input.as_str();
+        assert_eq!(word, "bar");
+        assert_eq!(span.range(), 104..107);
+        assert_eq!(span.start.column, 5);
 
-        if input.starts_with(val) {
-            let (_, rest) = input.split_at(val.len());
+        let position = Position {
+            line: 3,
+            column: 7,
+            offset: 20,
+        };
 
-            self.input = rest.chars();
+        let mut reader = StringReader::new("a\nb").with_position(position);
 
-            // Peek for the next character without advancing the input.
-            self.current = self.input.clone().next();
+        reader.skip_line();
 
-            Ok(())
-        } else {
-            Err(ParseError::NoMatch)
-        }
+        assert_eq!(reader.position().line, 4);
+        assert_eq!(reader.position().column, 1);
+        assert_eq!(reader.position().offset, 22);
     }
 
-    /// Read until a given condition is true or until the end of the input and
-    /// return the string.
-    ///
-    /// # Arguments
-    ///
-    /// * `cnd` - a closure that takes a single character and returns
-    /// true/false
-    pub fn read_until<F>(&mut self, cnd: F) -> &'a str
-    where
-        F: FnMut(char) -> bool,
-    {
-        let rest = self.input.as_str();
+    #[test]
+    fn test_debug() {
+        let mut reader = StringReader::new("foo\nbar \"baz\" qux quux");
 
-        let index = rest.find(cnd).unwrap_or_else(|| rest.len());
+        reader.read_word();
 
-        self.split_to(index)
+        assert_eq!(
+            format!("{:?}", reader),
+            "StringReader { position: 1:4, next: '\\n', rest: \"\\nbar \\\"baz\\\" qux q\u{2026}\" }"
+        );
+
+        reader.read_until(|_| false);
+
+        assert_eq!(
+            format!("{:?}", reader),
+            "StringReader { position: 2:19, next: None, rest: \"\" }"
+        );
     }
 
-    /// Read one word from the input and return it. A word ends with the first
-    /// whitespace character or with the end of the input. The method skips all
-    /// initial whitespace characters (if any).
-    #[inline]
-    pub fn read_word(&mut self) -> &'a str {
-        self.skip_whitespace();
-        self.read_until(char::is_whitespace)
+    #[test]
+    fn test_read_word_with() {
+        let mut reader = StringReader::new(", a,,b c ,");
+
+        let is_comma = |c| c == ',';
+
+        assert_eq!(reader.read_word_with(is_comma), " a");
+        assert_eq!(reader.read_word_with(is_comma), "b c ");
+        assert_eq!(reader.as_str(), ",");
+        assert_eq!(reader.read_word_with(is_comma), "");
+        assert!(reader.is_empty());
     }
 
-    /// Read the next word and parse it. The input won't be advanced if the
-    /// word cannot be parsed.
-    pub fn parse_word<T>(&mut self) -> Result<T, T::Err>
-    where
-        T: FromStr,
-    {
-        let (word, rest) = self.first_word();
+    #[test]
+    fn test_read_word_lowercase_into() {
+        let mut reader = StringReader::new("  HeLLo ŽLUŤOUČKÝ İx\n");
 
-        let parsed = word.parse()?;
+        let mut buf = [0u8; 16];
 
-        self.input = rest.chars();
+        assert_eq!(reader.read_word_lowercase_into(&mut buf), Ok("hello"));
 
-        // Peek for the next character without advancing the input.
-        self.current = self.input.clone().next();
+        let err = reader.read_word_lowercase_into(&mut buf[..12]).unwrap_err();
 
-        Ok(parsed)
-    }
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 9);
+        assert_eq!(reader.as_str(), " ŽLUŤOUČKÝ İx\n");
 
-    /// Read a decimal integer as i8.
-    #[inline]
-    pub fn read_i8(&mut self) -> Result<i8, ParseIntError> {
-        self.parse_word()
-    }
+        assert_eq!(reader.read_word_lowercase_into(&mut buf), Ok("žluťoučký"));
 
-    /// Read a decimal integer as u8.
-    #[inline]
-    pub fn read_u8(&mut self) -> Result<u8, ParseIntError> {
-        self.parse_word()
+        // the lowercase mapping of 'İ' is longer than the character itself
+        assert_eq!(reader.read_word_lowercase_into(&mut buf), Ok("i\u{307}x"));
+        assert_eq!(reader.read_word_lowercase_into(&mut buf), Ok(""));
+        assert!(reader.is_empty());
     }
 
-    /// Read a decimal integer as i16.
-    #[inline]
-    pub fn read_i16(&mut self) -> Result<i16, ParseIntError> {
-        self.parse_word()
-    }
+    #[test]
+    fn test_strict_words() {
+        let mut reader = StringReader::new("GET  /index.html 1.1").with_strict_words();
 
-    /// Read a decimal integer as u16.
-    #[inline]
-    pub fn read_u16(&mut self) -> Result<u16, ParseIntError> {
-        self.parse_word()
-    }
+        assert_eq!(reader.read_word(), "GET");
 
-    /// Read a decimal integer as i32.
-    #[inline]
-    pub fn read_i32(&mut self) -> Result<i32, ParseIntError> {
-        self.parse_word()
-    }
+        reader.match_char(' ').unwrap();
 
-    /// Read a decimal integer as u32.
-    #[inline]
-    pub fn read_u32(&mut self) -> Result<u32, ParseIntError> {
-        self.parse_word()
-    }
+        assert_eq!(reader.peek_word(), "");
+        assert_eq!(reader.read_word(), "");
 
-    /// Read a decimal integer as i64.
-    #[inline]
-    pub fn read_i64(&mut self) -> Result<i64, ParseIntError> {
-        self.parse_word()
-    }
+        reader.skip_whitespace();
 
-    /// Read a decimal integer as u64.
-    #[inline]
-    pub fn read_u64(&mut self) -> Result<u64, ParseIntError> {
-        self.parse_word()
-    }
+        assert_eq!(reader.read_word(), "/index.html");
+        assert!(reader.parse_word::<f32>().is_err());
+        assert!(reader.read_f32().is_err());
+        assert_eq!(reader.as_str(), " 1.1");
 
-    /// Read a decimal integer as i128.
-    #[inline]
-    pub fn read_i128(&mut self) -> Result<i128, ParseIntError> {
-        self.parse_word()
-    }
+        reader.skip_char();
 
-    /// Read a decimal integer as u128.
-    #[inline]
-    pub fn read_u128(&mut self) -> Result<u128, ParseIntError> {
-        self.parse_word()
+        assert_eq!(reader.read_f32(), Ok(1.1));
+        assert!(reader.is_empty());
     }
 
-    /// Read a decimal integer as isize.
-    #[inline]
-    pub fn read_isize(&mut self) -> Result<isize, ParseIntError> {
-        self.parse_word()
-    }
+    #[test]
+    fn test_read_line() {
+        let mut reader = StringReader::new("Host: foo\r\nA: b\rc\n\r\nbody\r");
 
-    /// Read a decimal integer as usize.
-    #[inline]
-    pub fn read_usize(&mut self) -> Result<usize, ParseIntError> {
-        self.parse_word()
-    }
+        assert_eq!(reader.read_line(), "Host: foo");
+        assert_eq!(reader.position().line, 2);
 
-    /// Read a floating point number as f32.
-    #[inline]
-    pub fn read_f32(&mut self) -> Result<f32, ParseFloatError> {
-        self.parse_word()
-    }
+        reader.skip_line();
 
-    /// Read a floating point number as f64.
-    #[inline]
-    pub fn read_f64(&mut self) -> Result<f64, ParseFloatError> {
-        self.parse_word()
+        assert_eq!(reader.read_line(), "");
+        assert_eq!(reader.read_line(), "body\r");
+        assert!(reader.is_empty());
+        assert_eq!(reader.read_line(), "");
     }
 
-    /// Check if the reader is empty.
-    #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.current_char().is_none()
-    }
+    #[test]
+    fn test_match_one_of() {
+        let mut reader = StringReader::new("+*x");
 
-    /// Get the rest of the input.
-    #[inline]
-    pub fn as_str(&self) -> &'a str {
-        self.input.as_str()
-    }
+        assert_eq!(reader.peek_one_of("+-"), Some('+'));
+        assert_eq!(reader.match_one_of("+-*/"), Ok('+'));
+        assert_eq!(reader.peek_one_of("+-"), None);
+        assert_eq!(reader.match_one_of("+-*/"), Ok('*'));
 
-    /// Get the first word and the remainder of the input without advancing the
-    /// input.
-    fn first_word(&self) -> (&'a str, &'a str) {
-        let input = self.input.as_str().trim_start();
+        let err = reader.match_one_of("+-*/").unwrap_err();
 
-        let index = input
-            .find(char::is_whitespace)
-            .unwrap_or_else(|| input.len());
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('x'));
+        assert_eq!(
+            err.to_string(),
+            "the input does not match at 1:3: expected one of \"+-*/\", found 'x'"
+        );
 
-        input.split_at(index)
+        reader.skip_char();
+
+        assert_eq!(
+            reader.match_one_of("+-*/").unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
     }
 
-    /// Split the input at a given index and return the first part.
-    ///
-    /// The input will contain the remaining part after this operation.
-    fn split_to(&mut self, index: usize) -> &'a str {
-        let rest = self.input.as_str();
+    #[test]
+    fn test_expectation_tracking() {
+        let mut reader = StringReader::new("let x = ;");
 
-        let (word, rest) = rest.split_at(index);
+        assert_eq!(reader.expected_set(), None);
+        assert!(reader.match_char('x').is_err());
+        assert_eq!(reader.expected_set(), None);
 
-        self.input = rest.chars();
+        let mut reader = reader.with_expectation_tracking();
 
-        // Peek for the next character without advancing the input.
-        self.current = self.input.clone().next();
+        assert!(reader.expected_set().unwrap().is_empty());
 
-        word
-    }
-}
+        reader.match_str("let ").unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::StringReader;
+        assert!(reader.match_str("fn").is_err());
+        assert!(reader.match_str("xy").is_err());
+
+        let expected = reader.expected_set().unwrap();
+
+        assert_eq!(
+            expected.as_slice(),
+            [Expected::Str(crate::InlineStr::new("xy"))]
+        );
+        assert_eq!(expected.position().column, 6);
+
+        reader.read_word();
+        reader.match_str(" = ").unwrap();
+
+        let checkpoint = reader.checkpoint();
+
+        assert!(reader.match_one_of("+-").is_err());
+        assert!(reader.match_char('(').is_err());
+        assert!(reader.match_char('(').is_err());
+        assert!(reader
+            .track_expected::<()>(Expected::Description("number"))
+            .is_err());
+
+        reader.rewind(checkpoint);
+
+        let expected = reader.expected_set().unwrap();
+
+        assert_eq!(expected.len(), 3);
+        assert_eq!(
+            expected.to_string(),
+            "expected one of one of \"+-\", '(', number at 1:9, found ';'"
+        );
+
+        reader.match_char(';').unwrap();
+
+        assert!(reader.match_char(';').is_err());
+
+        let expected = reader.expected_set().unwrap();
+
+        assert_eq!(expected.iter().collect::<Vec<_>>(), [&Expected::Char(';')]);
+        assert_eq!(
+            expected.to_string(),
+            "expected ';' at 1:10, found end of input"
+        );
+    }
 
     #[test]
-    fn test_reader() {
-        let input = "Hello, World!!!   1234\n\tfoo-bar";
+    fn test_number_error() {
+        fn parse(input: &str) -> Result<(u8, f32), ParseError> {
+            let mut reader = StringReader::new(input);
 
-        let mut reader = StringReader::new(input);
+            Ok((reader.read_u8()?, reader.read_f32()?))
+        }
 
-        assert!(!reader.is_empty());
-        assert_eq!(reader.current_char(), Some('H'));
-        assert_eq!(reader.as_str(), input);
+        assert_eq!(parse("1 2.5"), Ok((1, 2.5)));
 
-        let word = reader.read_word();
+        let err = parse("256 1").unwrap_err();
 
-        assert_eq!(word, "Hello,");
-        assert_eq!(reader.as_str(), " World!!!   1234\n\tfoo-bar");
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(
+            parse("1 x").unwrap_err().to_string(),
+            "invalid number at 1:3: expected floating point number, found 'x'"
+        );
+
+        let mut reader = StringReader::new("12\n 300 1.5x ");
+
+        assert_eq!(reader.read_u8(), Ok(12));
+
+        let err = reader.read_u8().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::PosOverflow))
+        );
+        assert_eq!(err.found(), Some('3'));
+        assert_eq!(
+            err.to_string(),
+            "invalid number at 2:2: expected integer, found '3'"
+        );
+
+        reader.read_word();
+
+        let err = reader.read_f32().unwrap_err();
+
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Float(FloatErrorKind::Invalid))
+        );
+        assert_eq!(err.position().column, 6);
+
+        reader.read_word();
+
+        let err = reader.read_u8().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::Empty))
+        );
+
+        let err = reader.read_float_inline::<f64>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.found(), Some(' '));
 
         reader.skip_whitespace();
 
-        assert_eq!(reader.as_str(), "World!!!   1234\n\tfoo-bar");
+        let err = reader.read_float_inline::<f64>().unwrap_err();
 
-        let c = reader.read_char();
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, 13);
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Float(FloatErrorKind::Empty))
+        );
 
-        assert_eq!(c.ok(), Some('W'));
+        // errors of the standard parsers keep the cause
+        let err = ParseError::from("x".parse::<u8>().unwrap_err());
 
-        let res = reader.match_char('o');
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.position(), Position::START);
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::InvalidDigit))
+        );
 
-        assert!(res.is_ok());
+        let err = ParseError::from("".parse::<f64>().unwrap_err());
 
-        let res = reader.match_char('R');
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Float(FloatErrorKind::Empty))
+        );
+    }
 
-        assert!(res.is_err());
+    #[test]
+    fn test_display_with_source() {
+        let input = "a\n\tb\r\n\n\n\n\n\n\n\n\tcd x";
 
-        let res = reader.match_str("RLD!!!");
+        let mut reader = StringReader::new(input);
 
-        assert!(res.is_err());
+        reader.read_word();
+        reader.skip_whitespace();
 
-        let res = reader.match_str("rld!!!");
+        let err = reader.match_char('x').unwrap_err();
 
-        assert!(res.is_ok());
-        assert_eq!(reader.as_str(), "   1234\n\tfoo-bar");
+        assert_eq!(
+            err.display_with_source(input).to_string(),
+            "the input does not match at 2:2: expected 'x', found 'b'\n  |\n2 | \tb\n  | \t^"
+        );
 
-        let n = reader.read_u32();
+        reader.read_word();
+        reader.read_word();
 
-        assert_eq!(n.ok(), Some(1234));
-        assert_eq!(reader.as_str(), "\n\tfoo-bar");
+        let err = reader.match_char('y').unwrap_err();
 
-        let n = reader.read_u32();
+        assert_eq!(
+            err.display_with_source(input).to_string(),
+            "the input does not match at 10:4: expected 'y', found ' '\n   |\n10 | \tcd x\n   | \t  ^"
+        );
 
-        assert!(n.is_err());
-        assert_eq!(reader.as_str(), "\n\tfoo-bar");
+        let err = reader.match_str(" x!").unwrap_err();
 
-        let word = reader.read_word();
+        assert!(err
+            .display_with_source(input)
+            .to_string()
+            .ends_with("10 | \tcd x\n   | \t    ^"));
+    }
 
-        assert_eq!(word, "foo-bar");
-        assert_eq!(reader.as_str(), "");
-        assert!(reader.is_empty());
+    #[test]
+    fn test_digits() {
+        let mut reader = StringReader::new("7f1012a");
 
-        let word = reader.read_word();
+        assert_eq!(reader.read_digit(10), Ok(7));
+        assert_eq!(reader.read_digit(16), Ok(15));
+        assert_eq!(reader.read_digits(2), "101");
+        assert_eq!(reader.read_digits(2), "");
 
-        assert_eq!(word, "");
+        let err = reader.read_digit(2).unwrap_err();
 
-        let c = reader.read_char();
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('2'));
+        assert_eq!(err.position().column, 6);
 
-        assert!(c.is_err());
-        assert!(reader.is_empty());
-        assert_eq!(reader.as_str(), "");
+        assert_eq!(reader.read_digits(36), "2a");
+        assert_eq!(
+            reader.read_digit(10).unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
     }
 }
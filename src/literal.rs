@@ -0,0 +1,406 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Read a quoted string. The string must start and end with a given
+    /// quote character. All escape sequences recognized by `read_escape()`
+    /// are recognized within the string. Additionally, the quote character
+    /// may be escaped as well. The input won't be advanced if the string
+    /// cannot be read.
+    ///
+    /// The returned string borrows the input if there are no escape sequences
+    /// in the string.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote` - quote character
+    #[cfg(feature = "alloc")]
+    pub fn read_quoted_string(&mut self, quote: char) -> Result<Cow<'a, str>, ParseError> {
+        self.transaction(|reader| {
+            reader.match_char(quote)?;
+
+            let is_special = |c| c == quote || c == '\\';
+
+            let s = reader.read_until(is_special);
+
+            if reader.current_char() == Some(quote) {
+                reader.skip_char();
+
+                return Ok(Cow::Borrowed(s));
+            }
+
+            let mut res = String::from(s);
+
+            loop {
+                match reader.current_char() {
+                    Some(c) if c == quote => break,
+                    Some('\\') => {
+                        let c = reader.read_escape_with(Some(quote))?;
+
+                        res.push(c);
+                    }
+                    Some(_) => res.push_str(reader.read_until(is_special)),
+                    None => {
+                        let err = reader
+                            .error(ErrorKind::EmptyInput)
+                            .with_expected(Expected::Char(quote));
+
+                        return Err(err);
+                    }
+                }
+            }
+
+            reader.skip_char();
+
+            Ok(Cow::Owned(res))
+        })
+    }
+
+    /// Read a single-quoted character literal (e.g. `'a'`, `'\n'` or
+    /// `'\u{1F600}'`) and return the decoded character. All escape sequences
+    /// recognized by `read_escape()` are recognized within the literal. The
+    /// literal cannot be empty and it cannot contain an unescaped quote or
+    /// line break. The input won't be advanced if the literal cannot be read.
+    pub fn read_char_literal(&mut self) -> Result<char, ParseError> {
+        self.transaction(|reader| {
+            reader.match_char('\'')?;
+
+            let c = match reader.current_char() {
+                Some('\\') => reader.read_escape()?,
+                Some(c) if c != '\'' && c != '\n' && c != '\r' => {
+                    reader.skip_char();
+
+                    c
+                }
+                c => {
+                    let kind = if c.is_some() {
+                        ErrorKind::NoMatch
+                    } else {
+                        ErrorKind::EmptyInput
+                    };
+
+                    let err = reader
+                        .error(kind)
+                        .with_expected(Expected::Description("character"))
+                        .with_found(c);
+
+                    return Err(err);
+                }
+            };
+
+            reader.match_char('\'')?;
+
+            Ok(c)
+        })
+    }
+
+    /// Read a key-value pair separated by a given separator (e.g.
+    /// `key=value`). The key ends with the separator or with a word delimiter
+    /// (whitespace by default). Word delimiters around the separator are
+    /// skipped. The value is either a quoted string (see
+    /// `read_quoted_string()`) or a bare word ending with a word delimiter.
+    /// Leading delimiters are skipped. The input won't be advanced in case of
+    /// an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `sep` - key-value separator
+    #[cfg(feature = "alloc")]
+    pub fn read_key_value(&mut self, sep: char) -> Result<(&'a str, Cow<'a, str>), ParseError> {
+        self.transaction(|reader| {
+            reader.skip_whitespace();
+
+            let delimiters = reader.delimiters;
+
+            let key = reader.read_until(|c| c == sep || delimiters.is_delimiter(c));
+
+            if key.is_empty() {
+                let err = reader
+                    .error(ErrorKind::NoMatch)
+                    .with_expected(Expected::Description("key"))
+                    .with_found(reader.current_char());
+
+                return Err(err);
+            }
+
+            reader.skip_whitespace();
+            reader.match_char(sep)?;
+            reader.skip_whitespace();
+
+            let value = if reader.current_char() == Some('"') {
+                reader.read_quoted_string('"')?
+            } else {
+                Cow::Borrowed(reader.read_until(|c| delimiters.is_delimiter(c)))
+            };
+
+            Ok((key, value))
+        })
+    }
+
+    /// Read an escape sequence starting with a backslash and decode it. The
+    /// following escape sequences are recognized:
+    ///
+    /// * `\\`, `\"` and `\'` - backslash and quotes
+    /// * `\n`, `\r`, `\t` and `\0` - line feed, carriage return, tab and
+    ///   null character
+    /// * `\xNN` - ASCII character with a given code (exactly two hexadecimal
+    ///   digits, at most `\x7f`)
+    /// * `\u{N...}` - Unicode code point (one to six hexadecimal digits);
+    ///   surrogates and values above `\u{10ffff}` are rejected
+    ///
+    /// The input won't be advanced if the escape sequence is not valid.
+    #[inline]
+    pub fn read_escape(&mut self) -> Result<char, ParseError> {
+        self.read_escape_with(None)
+    }
+
+    /// Read an escape sequence starting with a backslash. A given quote
+    /// character may be escaped as well.
+    fn read_escape_with(&mut self, quote: Option<char>) -> Result<char, ParseError> {
+        let start = self.checkpoint();
+
+        let invalid = |reader: &mut Self| {
+            let err = ParseError::new(ErrorKind::InvalidEscape, start.position())
+                .with_found(reader.current_char());
+
+            reader.rewind(start);
+
+            err
+        };
+
+        self.match_char('\\')?;
+
+        let c = match self.read_char() {
+            Ok(c) if Some(c) == quote => return Ok(c),
+            Ok(c) => c,
+            Err(_) => return Err(invalid(self)),
+        };
+
+        let res = match c {
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'x' => {
+                let digits = self.peek_str(2);
+
+                if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid(self));
+                }
+
+                let code = u8::from_str_radix(digits, 16).unwrap_or(0xff);
+
+                if code > 0x7f {
+                    return Err(invalid(self));
+                }
+
+                self.split_to(2);
+
+                code as char
+            }
+            'u' => {
+                if self.match_char('{').is_err() {
+                    return Err(invalid(self));
+                }
+
+                let digits = self.read_until(|c| !c.is_ascii_hexdigit());
+
+                if digits.is_empty() || digits.len() > 6 || self.match_char('}').is_err() {
+                    return Err(invalid(self));
+                }
+
+                // Surrogates and values above 0x10ffff are rejected here.
+                match u32::from_str_radix(digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                {
+                    Some(c) => c,
+                    None => return Err(invalid(self)),
+                }
+            }
+            _ => return Err(invalid(self)),
+        };
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use std::borrow::Cow;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_quoted_string() {
+        let mut reader = StringReader::new(r#""hello" "a\"b\\c\n\u{1F600}" 'it\'s' "#);
+
+        let s = reader.read_quoted_string('"').unwrap();
+
+        assert!(matches!(s, Cow::Borrowed("hello")));
+
+        reader.skip_whitespace();
+
+        let s = reader.read_quoted_string('"').unwrap();
+
+        assert!(matches!(s, Cow::Owned(_)));
+        assert_eq!(s, "a\"b\\c\n\u{1F600}");
+
+        reader.skip_whitespace();
+
+        assert!(reader.read_quoted_string('"').is_err());
+        assert_eq!(reader.read_quoted_string('\'').unwrap(), "it's");
+
+        let input = r#""abc\q" "\u{d800}" "\u{110000}" "unterminated\""#;
+
+        let mut reader = StringReader::new(input);
+
+        let err = reader.read_quoted_string('"').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+        assert_eq!(err.position().offset, 4);
+        assert_eq!(reader.as_str(), input);
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_quoted_string('"').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_quoted_string('"').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_quoted_string('"').unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(err.position().offset, input.len());
+        assert_eq!(reader.as_str(), "\"unterminated\\\"");
+    }
+
+    #[test]
+    fn test_char_literal() {
+        let mut reader = StringReader::new(r"'a' '\n''\u{1F600}' '\'' 'ab' '' '\q'");
+
+        assert_eq!(reader.read_char_literal(), Ok('a'));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_char_literal(), Ok('\n'));
+        assert_eq!(reader.read_char_literal(), Ok('\u{1F600}'));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_char_literal(), Ok('\''));
+
+        reader.skip_whitespace();
+
+        let err = reader.read_char_literal().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('b'));
+        assert_eq!(reader.as_str(), "'ab' '' '\\q'");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_char_literal().unwrap_err();
+
+        assert_eq!(err.found(), Some('\''));
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_char_literal().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+        assert_eq!(
+            StringReader::new("'")
+                .read_char_literal()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::EmptyInput
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        let mut reader = StringReader::new(r"\n\x41\u{10ffff}\\\x80\x4\u{}\u{1234567}\q");
+
+        assert_eq!(reader.read_escape(), Ok('\n'));
+        assert_eq!(reader.read_escape(), Ok('A'));
+        assert_eq!(reader.read_escape(), Ok('\u{10ffff}'));
+        assert_eq!(reader.read_escape(), Ok('\\'));
+
+        for len in &[4, 3, 4, 11, 2] {
+            let err = reader.read_escape().unwrap_err();
+
+            assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+
+            for _ in 0..*len {
+                reader.skip_char();
+            }
+        }
+
+        assert!(reader.is_empty());
+        assert_eq!(
+            reader.read_escape().unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_key_value() {
+        use crate::Delimiters;
+
+        let mut reader = StringReader::new(r#"charset=utf-8 name = "a \"b\"" x empty="#);
+
+        assert_eq!(
+            reader.read_key_value('='),
+            Ok(("charset", Cow::Borrowed("utf-8")))
+        );
+
+        let (key, value) = reader.read_key_value('=').unwrap();
+
+        assert_eq!(key, "name");
+        assert_eq!(value, "a \"b\"");
+
+        let err = reader.read_key_value('=').unwrap_err();
+
+        assert_eq!(err.found(), Some('e'));
+        assert_eq!(reader.as_str(), " x empty=");
+
+        reader.read_word();
+
+        assert_eq!(reader.read_key_value('='), Ok(("empty", Cow::Borrowed(""))));
+
+        let mut reader = StringReader::new("=foo");
+
+        assert_eq!(
+            reader.read_key_value('=').unwrap_err().kind(),
+            ErrorKind::NoMatch
+        );
+
+        // the configured delimiters are skipped around the separator
+        let mut reader =
+            StringReader::new("a,:,b,c").with_delimiters(Delimiters::Custom(|c| c == ','));
+
+        assert_eq!(reader.read_key_value(':'), Ok(("a", Cow::Borrowed("b"))));
+        assert_eq!(reader.as_str(), ",c");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    http::{self, Params, Value},
+    ParseError, StringReader,
+};
+
+/// Media type (MIME type) with parameters, e.g. `text/html; charset=utf-8`.
+///
+/// The media type borrows the input and its parameters are decoded lazily.
+/// The media type can be read using `StringReader::read_mime_type()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MediaType<'a> {
+    ty: &'a str,
+    subtype: &'a str,
+    params: Params<'a>,
+}
+
+impl<'a> MediaType<'a> {
+    /// Get the type (e.g. `text`).
+    #[inline]
+    pub fn ty(&self) -> &'a str {
+        self.ty
+    }
+
+    /// Get the subtype (e.g. `html`).
+    #[inline]
+    pub fn subtype(&self) -> &'a str {
+        self.subtype
+    }
+
+    /// Get the parameters.
+    #[inline]
+    pub fn params(&self) -> Params<'a> {
+        self.params
+    }
+
+    /// Get value of a given parameter. Parameter names are
+    /// case-insensitive.
+    #[inline]
+    pub fn param(&self, name: &str) -> Option<Value<'a>> {
+        self.params.get(name)
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read a media type (MIME type) with optional parameters starting at the
+    /// current position (e.g. `multipart/form-data; boundary="a b"`).
+    ///
+    /// The type and the subtype are tokens as defined by RFC 9110. See
+    /// `http::read_params()` for more info about the parameters. The input
+    /// won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("text/html; Charset=\"utf-8\", text/plain");
+    ///
+    /// let mime = reader.read_mime_type().unwrap();
+    ///
+    /// assert_eq!(mime.ty(), "text");
+    /// assert_eq!(mime.subtype(), "html");
+    /// assert_eq!(mime.param("charset").unwrap(), "utf-8");
+    /// assert_eq!(reader.as_str(), ", text/plain");
+    /// ```
+    pub fn read_mime_type(&mut self) -> Result<MediaType<'a>, ParseError> {
+        self.transaction(|reader| {
+            let ty = http::read_token(reader)?;
+
+            reader.match_char('/')?;
+
+            let subtype = http::read_token(reader)?;
+
+            let params = http::read_params(reader)?;
+
+            let res = MediaType {
+                ty,
+                subtype,
+                params,
+            };
+
+            Ok(res)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{http::Value, ErrorKind, StringReader};
+
+    #[test]
+    fn test_mime_type() {
+        let mut reader = StringReader::new(
+            "multipart/form-data ;boundary=\"a \\\"b\\\"\";; q=1 ,application/json;x",
+        );
+
+        let mime = reader.read_mime_type().unwrap();
+
+        assert_eq!(mime.ty(), "multipart");
+        assert_eq!(mime.subtype(), "form-data");
+        assert_eq!(
+            mime.params().iter().collect::<Vec<_>>(),
+            [
+                ("boundary", Value::Quoted("a \\\"b\\\"")),
+                ("q", Value::Token("1")),
+            ]
+        );
+        assert_eq!(mime.param("boundary").unwrap(), "a \"b\"");
+        assert_eq!(mime.param("Q").unwrap(), "1");
+        assert_eq!(mime.param("charset"), None);
+        assert_eq!(reader.as_str(), " ,application/json;x");
+
+        reader.match_str(" ,").unwrap();
+
+        let err = reader.read_mime_type().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(reader.as_str(), "application/json;x");
+
+        let mime = StringReader::new("text/plain").read_mime_type().unwrap();
+
+        assert_eq!(mime.params().iter().count(), 0);
+
+        for input in &[
+            "text",
+            "/plain",
+            "text/plain; a=\"b",
+            "text/plain; a=\"\\\u{7f}\"",
+        ] {
+            assert!(StringReader::new(input).read_mime_type().is_err());
+        }
+    }
+}
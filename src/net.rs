@@ -0,0 +1,402 @@
+use core::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    str::FromStr,
+};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Maximum length of a hostname label.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Maximum length of a hostname (excluding the trailing dot).
+const MAX_HOSTNAME_LEN: usize = 253;
+
+/// Maximum length of a textual IPv4 address.
+const MAX_IPV4_LEN: usize = 15;
+
+/// Maximum length of a textual IPv6 address.
+const MAX_IPV6_LEN: usize = 45;
+
+impl<'a> StringReader<'a> {
+    /// Read a hostname starting at the current position.
+    ///
+    /// A hostname consists of dot-separated labels. Each label must be 1 to
+    /// 63 characters long, it may contain ASCII letters, digits and hyphens
+    /// and it cannot start or end with a hyphen. The whole hostname cannot be
+    /// longer than 253 characters (excluding an optional trailing dot). The
+    /// method stops at the first character that cannot be part of the
+    /// hostname. The input won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("www.example.com:8080/index.html");
+    ///
+    /// assert_eq!(reader.read_hostname(), Ok("www.example.com"));
+    /// assert_eq!(reader.as_str(), ":8080/index.html");
+    /// ```
+    pub fn read_hostname(&mut self) -> Result<&'a str, ParseError> {
+        let input = self.as_str();
+        let bytes = input.as_bytes();
+
+        let mut len = 0;
+
+        loop {
+            let label = &bytes[len..];
+
+            let label_len = label
+                .iter()
+                .position(|&b| !is_label_byte(b))
+                .unwrap_or(label.len());
+
+            if label_len == 0 {
+                if len == 0 {
+                    let kind = if input.is_empty() {
+                        ErrorKind::EmptyInput
+                    } else {
+                        ErrorKind::NoMatch
+                    };
+
+                    let err = self
+                        .error(kind)
+                        .with_expected(Expected::Description("hostname"))
+                        .with_found(self.current_char());
+
+                    return Err(err);
+                }
+
+                // trailing dot of a fully qualified name
+                break;
+            }
+
+            let label = &input[len..len + label_len];
+
+            if label.starts_with('-') || label.ends_with('-') || label_len > MAX_LABEL_LEN {
+                let position = self.position_of(&input[len..]);
+
+                let err = ParseError::new(ErrorKind::InvalidValue, position)
+                    .with_expected(Expected::Description("hostname label"))
+                    .with_found(label.chars().next());
+
+                return Err(err);
+            }
+
+            len += label_len;
+
+            if bytes.get(len) == Some(&b'.') {
+                len += 1;
+            } else {
+                break;
+            }
+        }
+
+        let hostname_len = input[..len].strip_suffix('.').map(str::len).unwrap_or(len);
+
+        if hostname_len > MAX_HOSTNAME_LEN {
+            let err = self
+                .error(ErrorKind::InvalidValue)
+                .with_expected(Expected::Description("hostname"))
+                .with_found(self.current_char());
+
+            return Err(err);
+        }
+
+        Ok(self.split_to(len))
+    }
+
+    /// Read an IPv4 address in the dotted decimal notation starting at the
+    /// current position. The method stops at the first character that cannot
+    /// be part of the address. The input won't be advanced in case of an
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    ///
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("127.0.0.1:8080/path");
+    ///
+    /// assert_eq!(reader.read_ipv4(), Ok(Ipv4Addr::LOCALHOST));
+    /// assert_eq!(reader.as_str(), ":8080/path");
+    /// ```
+    #[inline]
+    pub fn read_ipv4(&mut self) -> Result<Ipv4Addr, ParseError> {
+        self.read_address(
+            MAX_IPV4_LEN,
+            "IPv4 address",
+            |b| b.is_ascii_digit(),
+            |b| b.is_ascii_digit() || b == b'.',
+        )
+    }
+
+    /// Read an IPv6 address starting at the current position. The address
+    /// may contain an embedded IPv4 address (e.g. `::ffff:10.0.0.1`). Zone
+    /// identifiers are not supported. The method stops at the first character
+    /// that cannot be part of the address. The input won't be advanced in
+    /// case of an error.
+    #[inline]
+    pub fn read_ipv6(&mut self) -> Result<Ipv6Addr, ParseError> {
+        self.read_address(
+            MAX_IPV6_LEN,
+            "IPv6 address",
+            |b| b.is_ascii_hexdigit(),
+            |b| b.is_ascii_hexdigit() || b == b':' || b == b'.',
+        )
+    }
+
+    /// Read a socket address starting at the current position. The address
+    /// is either an IPv4 address followed by a port (e.g. `10.0.0.1:80`) or
+    /// an IPv6 address in square brackets followed by a port (e.g.
+    /// `[::1]:80`). The input won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::net::{Ipv6Addr, SocketAddr};
+    ///
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("[::1]:8080/path");
+    ///
+    /// assert_eq!(
+    ///     reader.read_socket_addr(),
+    ///     Ok(SocketAddr::from((Ipv6Addr::LOCALHOST, 8080)))
+    /// );
+    /// assert_eq!(reader.as_str(), "/path");
+    /// ```
+    pub fn read_socket_addr(&mut self) -> Result<SocketAddr, ParseError> {
+        self.transaction(|reader| {
+            if reader.current_char() == Some('[') {
+                reader.skip_char();
+
+                let ip = reader.read_ipv6()?;
+
+                reader.match_str("]:")?;
+
+                let port = reader.read_port()?;
+
+                Ok(SocketAddrV6::new(ip, port, 0, 0).into())
+            } else {
+                let ip = reader.read_ipv4()?;
+
+                reader.match_char(':')?;
+
+                let port = reader.read_port()?;
+
+                Ok(SocketAddrV4::new(ip, port).into())
+            }
+        })
+    }
+
+    /// Read a port number.
+    fn read_port(&mut self) -> Result<u16, ParseError> {
+        let position = self.position;
+
+        let digits = self.as_str();
+        let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+        let digits = &digits[..len];
+
+        if digits.is_empty() {
+            let err = self
+                .error(ErrorKind::NoMatch)
+                .with_expected(Expected::Description("port"))
+                .with_found(self.current_char());
+
+            return Err(err);
+        }
+
+        let port = crate::parse::parse_value(digits, position, "port")?;
+
+        self.split_to(len);
+
+        Ok(port)
+    }
+
+    /// Read an address starting at the current position. The address is the
+    /// longest prefix of the input (up to a given length) consisting of
+    /// allowed bytes that can be parsed and that is not followed by a byte
+    /// that could continue the last address component.
+    fn read_address<T, C, A>(
+        &mut self,
+        max_len: usize,
+        expected: &'static str,
+        is_continuation: C,
+        is_allowed: A,
+    ) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        C: Fn(u8) -> bool,
+        A: Fn(u8) -> bool,
+    {
+        let input = self.as_str();
+        let bytes = input.as_bytes();
+
+        let candidate_len = bytes.iter().take_while(|&&b| is_allowed(b)).count();
+
+        for len in (1..=candidate_len.min(max_len)).rev() {
+            if bytes.get(len).copied().map(&is_continuation) == Some(true) {
+                continue;
+            }
+
+            if let Ok(addr) = input[..len].parse() {
+                self.split_to(len);
+
+                return Ok(addr);
+            }
+        }
+
+        let kind = if input.is_empty() {
+            ErrorKind::EmptyInput
+        } else if candidate_len > 0 {
+            ErrorKind::InvalidValue
+        } else {
+            ErrorKind::NoMatch
+        };
+
+        let err = self
+            .error(kind)
+            .with_expected(Expected::Description(expected))
+            .with_found(self.current_char());
+
+        Err(err)
+    }
+}
+
+/// Check if a given byte can be part of a hostname label.
+fn is_label_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-'
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_hostname() {
+        let mut reader = StringReader::new("foo-1.Example.org/path");
+
+        assert_eq!(reader.read_hostname(), Ok("foo-1.Example.org"));
+        assert_eq!(reader.as_str(), "/path");
+
+        let mut reader = StringReader::new("example.com. A");
+
+        assert_eq!(reader.read_hostname(), Ok("example.com."));
+        assert_eq!(reader.as_str(), " A");
+
+        let mut reader = StringReader::new("foo.-bar.com");
+
+        let err = reader.read_hostname().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 5);
+        assert_eq!(reader.as_str(), "foo.-bar.com");
+
+        let label = "a".repeat(64);
+
+        assert!(StringReader::new(&label).read_hostname().is_err());
+        assert!(StringReader::new(&label[1..]).read_hostname().is_ok());
+
+        let hostname = [
+            "a".repeat(63),
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(62),
+        ]
+        .join(".");
+
+        assert!(StringReader::new(&hostname).read_hostname().is_err());
+        assert!(StringReader::new(&hostname[1..]).read_hostname().is_ok());
+
+        assert_eq!(
+            StringReader::new("_foo")
+                .read_hostname()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NoMatch
+        );
+        assert_eq!(
+            StringReader::new("").read_hostname().unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+    }
+
+    #[test]
+    fn test_ip_addr() {
+        let mut reader = StringReader::new("10.0.0.1. 1.2.3.456 1.2.3 x");
+
+        assert_eq!(reader.read_ipv4(), Ok(Ipv4Addr::new(10, 0, 0, 1)));
+        assert_eq!(reader.as_str(), ". 1.2.3.456 1.2.3 x");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let err = reader.read_ipv4().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(reader.as_str(), "1.2.3.456 1.2.3 x");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.read_ipv4().is_err());
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_ipv4().unwrap_err().kind(), ErrorKind::NoMatch);
+
+        let mut reader = StringReader::new("fe80::1:/64 ::ffff:10.0.0.1] ::12345");
+
+        assert_eq!(
+            reader.read_ipv6(),
+            Ok(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))
+        );
+        assert_eq!(reader.as_str(), ":/64 ::ffff:10.0.0.1] ::12345");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(
+            reader.read_ipv6(),
+            Ok(Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped())
+        );
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.read_ipv6().is_err());
+    }
+
+    #[test]
+    fn test_socket_addr() {
+        let mut reader = StringReader::new("127.0.0.1:8080/x [2001:db8::1]:443 1.2.3.4:70000");
+
+        assert_eq!(
+            reader.read_socket_addr(),
+            Ok(SocketAddr::from((Ipv4Addr::LOCALHOST, 8080)))
+        );
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let addr = reader.read_socket_addr().unwrap();
+
+        assert_eq!(addr.port(), 443);
+        assert_eq!(addr.ip(), "2001:db8::1".parse::<Ipv6Addr>().unwrap());
+
+        reader.skip_whitespace();
+
+        let err = reader.read_socket_addr().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().offset, 43);
+        assert_eq!(reader.as_str(), "1.2.3.4:70000");
+        assert!(StringReader::new("[::1]").read_socket_addr().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use ::nom::{error::Error, Err, Parser};
+
+use crate::{ErrorKind, ParseError, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Run a given `nom` parser on the remaining input and, if successful,
+    /// advance the input past the part consumed by the parser. It allows
+    /// mixing `nom` combinators with the reader methods in a single parser.
+    /// The remaining input is considered complete. Errors are converted to
+    /// `ParseError` pointing to the position reported by the parser. The
+    /// input won't be advanced in case of an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `parser` - the `nom` parser
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nom::{bytes::complete::tag, character::complete::digit1, sequence::preceded};
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("v=123 rest");
+    ///
+    /// let digits = reader.parse_nom(preceded(tag("v="), digit1)).unwrap();
+    ///
+    /// assert_eq!(digits, "123");
+    /// assert_eq!(reader.read_word(), "rest");
+    /// ```
+    pub fn parse_nom<P>(&mut self, mut parser: P) -> Result<P::Output, ParseError>
+    where
+        P: Parser<&'a str, Error = Error<&'a str>>,
+    {
+        let input = self.input.as_str();
+
+        match parser.parse_complete(input) {
+            Ok((rest, output)) => {
+                self.advance_to(rest);
+
+                Ok(output)
+            }
+            Err(Err::Error(err)) | Err(Err::Failure(err)) => {
+                // The remaining input reported by the parser should be a
+                // suffix of the input.
+                let rest = if input.ends_with(err.input) {
+                    err.input
+                } else {
+                    input
+                };
+
+                let err = ParseError::new(ErrorKind::NoMatch, self.position_of(rest))
+                    .with_found(rest.chars().next());
+
+                Err(err)
+            }
+            Err(Err::Incomplete(_)) => {
+                Err(ParseError::new(ErrorKind::Incomplete, self.end_position()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{alpha1, digit1},
+        sequence::separated_pair,
+        IResult, Parser,
+    };
+
+    use crate::{ErrorKind, StringReader};
+
+    fn pair(input: &str) -> IResult<&str, (&str, &str)> {
+        separated_pair(alpha1, tag("="), alt((digit1, alpha1))).parse(input)
+    }
+
+    #[test]
+    fn test_parse_nom() {
+        let mut reader = StringReader::new("x\nkey=42 key=v?");
+
+        reader.read_line();
+
+        assert_eq!(reader.parse_nom(pair), Ok(("key", "42")));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.parse_nom(pair), Ok(("key", "v")));
+        assert_eq!(reader.as_str(), "?");
+
+        let err = reader.parse_nom(pair).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('?'));
+        assert_eq!(err.position().line, 2);
+        assert_eq!(err.position().column, 13);
+        assert_eq!(reader.as_str(), "?");
+
+        let mut reader = StringReader::new("key=");
+
+        let err = reader.parse_nom(pair).unwrap_err();
+
+        assert_eq!(err.found(), None);
+        assert_eq!(err.position().offset, 4);
+        assert_eq!(reader.as_str(), "key=");
+    }
+}
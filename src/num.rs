@@ -0,0 +1,494 @@
+use core::num::{ParseFloatError, ParseIntError};
+
+mod private {
+    use core::num::ParseIntError;
+
+    use super::Overflow;
+
+    /// Sealing trait for the numeric traits.
+    pub trait Sealed: Sized {
+        /// Parse an integer from a given sequence of digits (without sign) in
+        /// a given radix handling overflows in a given way.
+        fn from_digits<I>(
+            negative: bool,
+            digits: I,
+            radix: u32,
+            overflow: Overflow,
+        ) -> Result<Self, ParseIntError>
+        where
+            I: IntoIterator<Item = char>;
+    }
+
+    /// Sealing trait for the floating point traits.
+    pub trait SealedFloat: Sized {}
+}
+
+/// Integer types that can be read by the string reader.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ReadableInt: private::Sealed {
+    /// Parse an integer from a given string in a given radix.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_readable_int {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {
+                fn from_digits<I>(
+                    negative: bool,
+                    digits: I,
+                    radix: u32,
+                    overflow: Overflow,
+                ) -> Result<Self, ParseIntError>
+                where
+                    I: IntoIterator<Item = char>,
+                {
+                    let mut digits = digits.into_iter().peekable();
+
+                    if digits.peek().is_none() {
+                        return Err(int_error::empty());
+                    } else if negative && <$t>::MIN == 0 && overflow == Overflow::Error {
+                        return Err(int_error::invalid_digit());
+                    }
+
+                    let (overflow_error, limit) = if negative {
+                        (int_error::neg_overflow as fn() -> ParseIntError, <$t>::MIN)
+                    } else {
+                        (int_error::pos_overflow as fn() -> ParseIntError, <$t>::MAX)
+                    };
+
+                    let mut res: $t = 0;
+                    let mut saturated = false;
+
+                    for c in digits {
+                        let digit = c.to_digit(radix).ok_or_else(int_error::invalid_digit)? as $t;
+
+                        if saturated {
+                            continue;
+                        }
+
+                        let next = res.checked_mul(radix as $t).and_then(|res| {
+                            if negative {
+                                res.checked_sub(digit)
+                            } else {
+                                res.checked_add(digit)
+                            }
+                        });
+
+                        res = match (next, overflow) {
+                            (Some(next), _) => next,
+                            (None, Overflow::Error) => return Err(overflow_error()),
+                            (None, Overflow::Saturate) => {
+                                saturated = true;
+                                limit
+                            }
+                            (None, Overflow::Wrap) => {
+                                let res = res.wrapping_mul(radix as $t);
+
+                                if negative {
+                                    res.wrapping_sub(digit)
+                                } else {
+                                    res.wrapping_add(digit)
+                                }
+                            }
+                        };
+                    }
+
+                    Ok(res)
+                }
+            }
+
+            impl ReadableInt for $t {
+                #[inline]
+                fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$t>::from_str_radix(s, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_readable_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+/// Floating point types that can be read by the string reader.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ReadableFloat: private::SealedFloat {
+    /// Parse a floating point number from a given string.
+    fn from_str(s: &str) -> Result<Self, ParseFloatError>;
+}
+
+macro_rules! impl_readable_float {
+    ($($t:ty),*) => {
+        $(
+            impl private::SealedFloat for $t {}
+
+            impl ReadableFloat for $t {
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, ParseFloatError> {
+                    s.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_readable_float!(f32, f64);
+
+/// Parse an integer literal with an optional sign and an optional radix
+/// prefix (`0x`, `0o` or `0b`).
+pub(crate) fn parse_int_auto<T>(s: &str) -> Result<T, ParseIntError>
+where
+    T: ReadableInt,
+{
+    let (negative, s) = if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    };
+
+    let bytes = s.as_bytes();
+
+    let radix = if bytes.len() < 2 || bytes[0] != b'0' {
+        10
+    } else {
+        match bytes[1] {
+            b'x' | b'X' => 16,
+            b'o' | b'O' => 8,
+            b'b' | b'B' => 2,
+            _ => 10,
+        }
+    };
+
+    let digits = if radix == 10 { s } else { &s[2..] };
+
+    T::from_digits(negative, digits.chars(), radix, Overflow::Error)
+}
+
+/// Get length of the longest decimal integer literal at the beginning of a
+/// given string. The literal consists of an optional sign and a sequence of
+/// digits.
+pub(crate) fn int_literal_len(s: &str, options: &NumberOptions) -> usize {
+    let sign_len = literal_sign_len(s, options);
+
+    scan_int_part(s, sign_len, options)
+}
+
+/// Parse a decimal integer literal.
+pub(crate) fn parse_int<T>(literal: &str, options: &NumberOptions) -> Result<T, ParseIntError>
+where
+    T: ReadableInt,
+{
+    let (negative, digits) = if let Some(rest) = literal.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, literal.strip_prefix('+').unwrap_or(literal))
+    };
+
+    // the sign may be followed by whitespace (see `literal_sign_len()`)
+    let digits = digits.trim_start();
+
+    let separator = options.digit_separator;
+    let group = options.group_separator;
+
+    let digits = digits
+        .chars()
+        .filter(|&c| Some(c) != separator && Some(c) != group);
+
+    T::from_digits(negative, digits, 10, options.overflow)
+}
+
+/// Get length of the longest decimal floating point literal at the beginning
+/// of a given string. The literal consists of an optional sign, integer part,
+/// fractional part and an exponent. Either the integer part or the fractional
+/// part must contain at least one digit.
+pub(crate) fn float_literal_len(s: &str, options: &NumberOptions) -> usize {
+    let separator = options.digit_separator;
+
+    let mut index = literal_sign_len(s, options);
+
+    let int_end = scan_int_part(s, index, options);
+
+    let mut has_digits = int_end > index;
+
+    index = int_end;
+
+    let point = options.decimal_separator;
+
+    if s[index..].starts_with(point) {
+        let frac_start = index + point.len_utf8();
+        let frac_end = scan_digits(s, frac_start, 10, separator);
+
+        has_digits |= frac_end > frac_start;
+
+        index = frac_end;
+    }
+
+    if !has_digits {
+        return 0;
+    }
+
+    if s[index..].starts_with(&['e', 'E'][..]) {
+        let exp_start = index + 1 + sign_len(&s[index + 1..]);
+        let exp_end = scan_digits(s, exp_start, 10, separator);
+
+        if exp_end > exp_start {
+            index = exp_end;
+        }
+    }
+
+    index
+}
+
+/// Parse a decimal floating point literal.
+pub(crate) fn parse_float<T>(literal: &str, options: &NumberOptions) -> Result<T, ParseFloatError>
+where
+    T: ReadableFloat,
+{
+    let separator = options.digit_separator;
+    let group = options.group_separator;
+    let point = options.decimal_separator;
+
+    let is_removed = |c: char| Some(c) == separator || Some(c) == group || c.is_whitespace();
+
+    if !literal.contains(|c| is_removed(c) || (c == point && c != '.')) {
+        return T::from_str(literal);
+    }
+
+    // We need to remove the separators (and whitespace after the sign) and
+    // replace the decimal separator before parsing. The literal contains only
+    // ASCII characters (apart from the removed and replaced ones).
+    let mut buf = [0u8; FLOAT_BUFFER_SIZE];
+    let mut len = 0;
+
+    for c in literal.chars().filter(|&c| !is_removed(c)) {
+        let c = if c == point { '.' } else { c };
+
+        if len == buf.len() || !c.is_ascii() {
+            return Err(float_error::invalid());
+        }
+
+        buf[len] = c as u8;
+
+        len += 1;
+    }
+
+    T::from_str(core::str::from_utf8(&buf[..len]).unwrap_or(""))
+}
+
+/// Maximum length of a floating point literal containing digit separators
+/// (excluding the separators).
+const FLOAT_BUFFER_SIZE: usize = 256;
+
+/// Get length of the sign of a literal at the beginning of a given string
+/// (including any whitespace following the sign if allowed by the options).
+fn literal_sign_len(s: &str, options: &NumberOptions) -> usize {
+    let len = match s.as_bytes().first() {
+        Some(b'-') => 1,
+        Some(b'+') if options.plus_sign => 1,
+        _ => return 0,
+    };
+
+    if options.sign_whitespace {
+        s.len() - s[len..].trim_start().len()
+    } else {
+        len
+    }
+}
+
+/// Get length of the sign at the beginning of a given string.
+fn sign_len(s: &str) -> usize {
+    if s.starts_with(&['+', '-'][..]) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Scan the integer part of a decimal literal starting at a given index and
+/// return index of the end of the integer part.
+fn scan_int_part(s: &str, start: usize, options: &NumberOptions) -> usize {
+    if let Some(group) = options.group_separator {
+        scan_grouped_digits(s, start, group)
+    } else {
+        scan_digits(s, start, 10, options.digit_separator)
+    }
+}
+
+/// Scan a sequence of decimal digits divided into groups of three by a given
+/// separator (e.g. `1,234,567`) starting at a given index and return index of
+/// the end of the sequence. The first group may contain one to three digits.
+/// A separator is accepted only if it is followed by exactly three digits.
+fn scan_grouped_digits(s: &str, start: usize, group: char) -> usize {
+    let digits = |index: usize| s[index..].bytes().take_while(u8::is_ascii_digit).count();
+
+    let first = digits(start);
+
+    let mut end = start + first;
+
+    if first == 0 || first > 3 {
+        return end;
+    }
+
+    while s[end..].starts_with(group) {
+        let group_start = end + group.len_utf8();
+
+        if digits(group_start) != 3 {
+            break;
+        }
+
+        end = group_start + 3;
+    }
+
+    end
+}
+
+/// Scan a sequence of digits in a given radix starting at a given index and
+/// return index of the end of the sequence. Digit separators are accepted
+/// between any two digits.
+fn scan_digits(s: &str, start: usize, radix: u32, separator: Option<char>) -> usize {
+    let mut end = start;
+
+    for (index, c) in s[start..].char_indices() {
+        if c.is_digit(radix) {
+            end = start + index + 1;
+        } else if end == start || Some(c) != separator {
+            break;
+        }
+    }
+
+    end
+}
+
+/// Options for reading numbers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NumberOptions {
+    digit_separator: Option<char>,
+    group_separator: Option<char>,
+    decimal_separator: char,
+    plus_sign: bool,
+    sign_whitespace: bool,
+    overflow: Overflow,
+}
+
+impl NumberOptions {
+    /// Create new number options with the default settings (i.e. no digit
+    /// separators, `.` as the decimal separator, the plus sign is accepted
+    /// and the sign must be directly followed by the number).
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            digit_separator: None,
+            group_separator: None,
+            decimal_separator: '.',
+            plus_sign: true,
+            sign_whitespace: false,
+            overflow: Overflow::Error,
+        }
+    }
+
+    /// Accept a given separator of thousands (e.g. `,` for `1,234,567`). The
+    /// separator may appear only between groups of three digits in the
+    /// integer part of a number. The first group may contain one to three
+    /// digits. The option takes precedence over `digit_separator()` in the
+    /// integer part. The separator must differ from the decimal separator.
+    #[inline]
+    pub fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    /// Use a given decimal separator for floating point numbers (e.g. `,`
+    /// for `3,14`). The default decimal separator is `.`.
+    #[inline]
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Accept (or reject) a leading plus sign (e.g. `+10`). The plus sign is
+    /// accepted by default.
+    #[inline]
+    pub fn plus_sign(mut self, enabled: bool) -> Self {
+        self.plus_sign = enabled;
+        self
+    }
+
+    /// Handle integer overflows in a given way. Overflows are reported as
+    /// errors by default (see `ParseError::number_error()` and
+    /// `NumberError::Int` for distinguishing them from syntax errors).
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Allow (or disallow) whitespace between the sign and the number (e.g.
+    /// `- 10`). Whitespace is not allowed by default.
+    #[inline]
+    pub fn sign_whitespace(mut self, enabled: bool) -> Self {
+        self.sign_whitespace = enabled;
+        self
+    }
+
+    /// Accept a given digit separator (e.g. `_`). The separator may appear
+    /// between any two digits, e.g. `1_000_000`.
+    #[inline]
+    pub fn digit_separator(mut self, separator: char) -> Self {
+        self.digit_separator = Some(separator);
+        self
+    }
+}
+
+/// Handling of integer overflows.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Report an error.
+    Error,
+    /// Clamp the value to the range of the integer type. Negative values are
+    /// clamped to zero for unsigned types.
+    Saturate,
+    /// Wrap the value around the boundary of the integer type.
+    Wrap,
+}
+
+impl Default for NumberOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helpers for constructing integer parsing errors.
+///
+/// `ParseIntError` cannot be constructed directly, so we let the standard
+/// library produce an error of the desired kind.
+mod int_error {
+    use core::num::ParseIntError;
+
+    pub fn empty() -> ParseIntError {
+        "".parse::<u8>().unwrap_err()
+    }
+
+    pub fn invalid_digit() -> ParseIntError {
+        "x".parse::<u8>().unwrap_err()
+    }
+
+    pub fn pos_overflow() -> ParseIntError {
+        "256".parse::<u8>().unwrap_err()
+    }
+
+    pub fn neg_overflow() -> ParseIntError {
+        "-129".parse::<i8>().unwrap_err()
+    }
+}
+
+/// Helpers for constructing floating point parsing errors.
+mod float_error {
+    use core::num::ParseFloatError;
+
+    pub fn invalid() -> ParseFloatError {
+        "x".parse::<f32>().unwrap_err()
+    }
+}
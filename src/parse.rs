@@ -0,0 +1,348 @@
+use core::str::FromStr;
+
+use crate::{ErrorKind, Expected, ParseError, Position, StringReader};
+
+/// A type that can be read from a string reader.
+///
+/// Unlike `FromStr`, the implementations consume only as much of the input
+/// as they need, so they can be nested naturally. The trait is implemented
+/// for all primitive types, `String`, `&str` (a single word) and tuples of up
+/// to eight readable types. Numbers are read inline (see
+/// `StringReader::read_int_inline()`), so they can be followed by other
+/// characters than whitespace. Booleans and strings are read as words.
+/// Characters are read one at a time. Leading whitespace is skipped before
+/// all primitive values unless the strict mode is enabled (see
+/// `StringReader::with_strict_words()`).
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{FromReader, ParseError, StringReader};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl<'a> FromReader<'a> for Point {
+///     fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+///         let (x, y) = reader.parse()?;
+///
+///         Ok(Self { x, y })
+///     }
+/// }
+///
+/// let mut reader = StringReader::new("1 2 3 4");
+///
+/// let (a, b): (Point, Point) = reader.parse().unwrap();
+///
+/// assert_eq!((a.x, a.y, b.x, b.y), (1, 2, 3, 4));
+/// ```
+pub trait FromReader<'a>: Sized {
+    /// Read the value from a given reader. Implementations are expected to
+    /// leave the reader unchanged in case of an error.
+    fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_reader_word {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> FromReader<'a> for $t {
+                #[inline]
+                fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+                    parse_word(reader, stringify!($t))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_reader_number {
+    ($read:ident => $($t:ty),*) => {
+        $(
+            impl<'a> FromReader<'a> for $t {
+                #[inline]
+                fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+                    read_number(reader, stringify!($t), StringReader::$read)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_reader_number!(
+    read_int_inline => i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize
+);
+
+impl_from_reader_number!(read_float_inline => f32, f64);
+
+impl_from_reader_word!(bool);
+
+#[cfg(feature = "alloc")]
+impl_from_reader_word!(alloc::string::String);
+
+impl<'a> FromReader<'a> for &'a str {
+    #[inline]
+    fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+        let (word, rest) = reader.first_word();
+
+        if word.is_empty() {
+            return Err(reader
+                .error(ErrorKind::EmptyInput)
+                .with_expected(Expected::Description("word")));
+        }
+
+        reader.advance_to(rest);
+
+        Ok(word)
+    }
+}
+
+impl<'a> FromReader<'a> for char {
+    #[inline]
+    fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+        reader.transaction(|reader| {
+            reader.advance_to(reader.word_start());
+            reader.read_char()
+        })
+    }
+}
+
+macro_rules! impl_from_reader_tuple {
+    ($($t:ident),+) => {
+        impl<'a, $($t),+> FromReader<'a> for ($($t,)+)
+        where
+            $($t: FromReader<'a>),+
+        {
+            fn from_reader(reader: &mut StringReader<'a>) -> Result<Self, ParseError> {
+                reader.transaction(|r| Ok(($($t::from_reader(r)?,)+)))
+            }
+        }
+    };
+}
+
+impl_from_reader_tuple!(A);
+impl_from_reader_tuple!(A, B);
+impl_from_reader_tuple!(A, B, C);
+impl_from_reader_tuple!(A, B, C, D);
+impl_from_reader_tuple!(A, B, C, D, E);
+impl_from_reader_tuple!(A, B, C, D, E, F);
+impl_from_reader_tuple!(A, B, C, D, E, F, G);
+impl_from_reader_tuple!(A, B, C, D, E, F, G, H);
+
+impl<'a> StringReader<'a> {
+    /// Read a value of a given type. See `FromReader` for more info.
+    #[inline]
+    pub fn parse<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromReader<'a>,
+    {
+        T::from_reader(self)
+    }
+}
+
+/// Skip all initial whitespace (unless the strict mode is enabled, see
+/// `StringReader::with_strict_words()`) and read a number using a given
+/// method. The error reported by the method is kept apart from the expected input which
+/// is replaced by a given type name. The input won't be advanced in case of
+/// an error.
+fn read_number<'a, T>(
+    reader: &mut StringReader<'a>,
+    expected: &'static str,
+    read: fn(&mut StringReader<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    reader.transaction(|reader| {
+        reader.advance_to(reader.word_start());
+
+        read(reader).map_err(|err| err.with_expected(Expected::Description(expected)))
+    })
+}
+
+/// Read the next word and parse it using `FromStr`. The input won't be
+/// advanced in case of an error.
+pub(crate) fn parse_word<'a, T>(
+    reader: &mut StringReader<'a>,
+    expected: &'static str,
+) -> Result<T, ParseError>
+where
+    T: FromStr,
+{
+    let (word, rest) = reader.first_word();
+
+    if word.is_empty() {
+        return Err(reader
+            .error(ErrorKind::EmptyInput)
+            .with_expected(Expected::Description(expected)));
+    }
+
+    let input = reader.input.as_str();
+
+    let position = reader.position_of(&input[input.len() - word.len() - rest.len()..]);
+
+    let parsed = parse_value(word, position, expected)?;
+
+    reader.advance_to(rest);
+
+    Ok(parsed)
+}
+
+/// Parse a given value using `FromStr` and report an `InvalidValue` error at
+/// a given position if the value cannot be parsed.
+pub(crate) fn parse_value<T>(
+    value: &str,
+    position: Position,
+    expected: &'static str,
+) -> Result<T, ParseError>
+where
+    T: FromStr,
+{
+    value.parse().map_err(|_| {
+        ParseError::new(ErrorKind::InvalidValue, position)
+            .with_expected(Expected::Description(expected))
+            .with_found(value.chars().next())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::IntErrorKind;
+
+    use crate::{ErrorKind, NumberError, StringReader};
+
+    #[test]
+    fn test_parse() {
+        let mut reader = StringReader::new("  12 -3.5 true foo a b x");
+
+        let (a, b, c): (u8, f32, bool) = reader.parse().unwrap();
+
+        assert_eq!((a, b, c), (12, -3.5, true));
+
+        // whitespace is skipped before characters as well
+        let (d, e): (&str, char) = reader.parse().unwrap();
+
+        assert_eq!((d, e), ("foo", 'a'));
+
+        let err = reader.parse::<(char, u32)>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(
+            err.to_string(),
+            "invalid number at 1:24: expected u32, found 'x'"
+        );
+        assert_eq!(reader.as_str(), " b x");
+
+        reader.read_word();
+
+        let err = reader.parse::<i64>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.kind(), reader.read_i64().unwrap_err().kind());
+        assert_eq!(
+            err.number_error(),
+            Some(NumberError::Int(IntErrorKind::Empty))
+        );
+        assert_eq!(err.position().column, 24);
+        assert_eq!(reader.as_str(), " x");
+
+        let err = reader.parse::<f32>().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid number at 1:24: expected f32, found 'x'"
+        );
+
+        reader.read_word();
+
+        let err = reader.parse::<(char, u32)>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+
+        let mut reader = StringReader::new("1,2;");
+
+        let (a, b, c, d): (u8, char, u8, char) = reader.parse().unwrap();
+
+        assert_eq!((a, b, c, d), (1, ',', 2, ';'));
+
+        let mut reader = StringReader::new("1  2").with_strict_words();
+
+        assert_eq!(reader.parse::<u8>(), Ok(1));
+
+        reader.match_char(' ').unwrap();
+
+        let err = reader.parse::<u8>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidNumber);
+        assert_eq!(err.found(), Some(' '));
+        assert_eq!(reader.as_str(), " 2");
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive() {
+        #[derive(crate::FromReader, Debug, PartialEq)]
+        struct Range(#[reader(suffix = "..")] u32, u32);
+
+        #[derive(crate::FromReader, Debug, PartialEq)]
+        struct Item {
+            #[reader(skip_whitespace, prefix = "[", suffix = "]")]
+            range: Range,
+            #[reader(optional, skip_whitespace, prefix = ":")]
+            step: Option<u32>,
+        }
+
+        let mut reader = StringReader::new("[1..5] : 2 [3..4] x");
+
+        let item = reader.parse::<Item>().unwrap();
+
+        assert_eq!(
+            item,
+            Item {
+                range: Range(1, 5),
+                step: Some(2),
+            }
+        );
+
+        let item = reader.parse::<Item>().unwrap();
+
+        assert_eq!(
+            item,
+            Item {
+                range: Range(3, 4),
+                step: None,
+            }
+        );
+
+        assert!(reader.parse::<Item>().is_err());
+        assert_eq!(reader.as_str(), " x");
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_generic() {
+        #[derive(crate::FromReader, Debug, PartialEq)]
+        struct Pair<'a, T> {
+            key: &'a str,
+            #[reader(skip_whitespace, prefix = "=")]
+            value: T,
+        }
+
+        #[derive(crate::FromReader, Debug, PartialEq)]
+        struct Wrapper<T>(#[reader(prefix = "(", suffix = ")")] T);
+
+        let mut reader = StringReader::new("a = 1 b =(x)");
+
+        let pair = reader.parse::<Pair<u8>>().unwrap();
+
+        assert_eq!(pair, Pair { key: "a", value: 1 });
+
+        let pair = reader.parse::<Pair<Wrapper<char>>>().unwrap();
+
+        assert_eq!(
+            pair,
+            Pair {
+                key: "b",
+                value: Wrapper('x'),
+            }
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::StringReader;
+
+impl<'a> StringReader<'a> {
+    /// Match a given pattern to the input and return the captured values.
+    /// The pattern consists of literal parts and placeholders (`{}`). Literal
+    /// parts are matched exactly (see `match_str()`). A placeholder captures
+    /// everything up to the next literal part or, if it is not followed by a
+    /// literal part, the next word. Captured values are trimmed of
+    /// surrounding whitespace. The input won't be advanced in case of an
+    /// error.
+    ///
+    /// See also the `read_fmt!` macro for parsing the captured values.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - input pattern
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the number of placeholders does not match `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("10-20: foo bar");
+    ///
+    /// let [a, b, c] = reader.read_pattern("{}-{}: {}").unwrap();
+    ///
+    /// assert_eq!((a, b, c), ("10", "20", "foo"));
+    /// assert_eq!(reader.as_str(), " bar");
+    /// ```
+    pub fn read_pattern<const N: usize>(
+        &mut self,
+        pattern: &str,
+    ) -> Result<[&'a str; N], crate::ParseError> {
+        let captures = __private::read_pattern_with_positions::<N>(self, pattern)?;
+
+        Ok(captures.map(|(capture, _)| capture))
+    }
+}
+
+/// Read values from a given reader using a pattern.
+///
+/// The macro takes a reader, a pattern (see `StringReader::read_pattern()`)
+/// and a list of types of the captured values. The captured values are
+/// parsed using `FromStr` and returned as a tuple. The reader won't be
+/// advanced in case of an error.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{read_fmt, StringReader};
+///
+/// let mut reader = StringReader::new("HTTP/1.1 404 Not Found");
+///
+/// let (version, status) = read_fmt!(reader, "HTTP/{} {}", String, u16).unwrap();
+///
+/// assert_eq!(version, "1.1");
+/// assert_eq!(status, 404);
+/// assert_eq!(reader.as_str(), " Not Found");
+/// ```
+#[macro_export]
+macro_rules! read_fmt {
+    ($reader:expr, $pattern:expr $(, $t:ty)* $(,)?) => {
+        $reader.transaction(|reader| -> ::core::result::Result<_, $crate::ParseError> {
+            let captures = $crate::__private::read_pattern_with_positions::<
+                { $crate::__count!($($t)*) },
+            >(reader, $pattern)?;
+
+            #[allow(unused_mut, unused_variables)]
+            let mut captures = captures.iter();
+
+            Ok(($($crate::__private::parse_capture::<$t>(captures.next())?,)*))
+        })
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __count {
+    () => {
+        0usize
+    };
+    ($head:tt $($tail:tt)*) => {
+        1usize + $crate::__count!($($tail)*)
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use core::{any, str::FromStr};
+
+    use crate::{ParseError, Position, StringReader};
+
+    /// Parse a captured value.
+    pub fn parse_capture<T>(capture: Option<&(&str, Position)>) -> Result<T, ParseError>
+    where
+        T: FromStr,
+    {
+        let (capture, position) = capture.copied().unwrap_or(("", Position::START));
+
+        crate::parse::parse_value(capture, position, any::type_name::<T>())
+    }
+
+    /// Match a given pattern to the input and return the captured values
+    /// together with their positions. See `StringReader::read_pattern()`.
+    pub fn read_pattern_with_positions<'a, const N: usize>(
+        reader: &mut StringReader<'a>,
+        pattern: &str,
+    ) -> Result<[(&'a str, Position); N], ParseError> {
+        reader.transaction(|reader| {
+            let mut res = [("", Position::START); N];
+
+            let mut parts = pattern.split("{}");
+
+            reader.match_str(parts.next().unwrap_or(""))?;
+
+            let mut count = 0;
+
+            for literal in parts {
+                assert!(count < N, "too many placeholders in the pattern");
+
+                reader.skip_while(char::is_whitespace);
+
+                let position = reader.position();
+
+                let capture = if literal.is_empty() {
+                    let delimiters = reader.delimiters;
+
+                    reader.read_until(|c| delimiters.is_delimiter(c))
+                } else {
+                    reader.read_until_str(literal)
+                };
+
+                res[count] = (capture.trim_end(), position);
+
+                reader.match_str(literal)?;
+
+                count += 1;
+            }
+
+            assert!(count == N, "not enough placeholders in the pattern");
+
+            Ok(res)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_pattern() {
+        let mut reader = StringReader::new("a= 1 ,b=2, c");
+
+        let [a, b] = reader.read_pattern("a={},b={},").unwrap();
+
+        assert_eq!((a, b), ("1", "2"));
+        assert_eq!(reader.as_str(), " c");
+
+        let mut reader = StringReader::new("foo bar");
+
+        assert!(reader.read_pattern::<1>("foo {}!").is_err());
+        assert_eq!(reader.as_str(), "foo bar");
+
+        let [] = reader.read_pattern("foo").unwrap();
+
+        assert_eq!(reader.as_str(), " bar");
+    }
+
+    #[test]
+    fn test_read_fmt() {
+        let mut reader = StringReader::new("12:34:56.5 x");
+
+        let (h, m, s) = read_fmt!(reader, "{}:{}:{}", u8, u8, f32).unwrap();
+
+        assert_eq!((h, m, s), (12, 34, 56.5));
+
+        let mut reader = StringReader::new("12:xy");
+
+        let err = read_fmt!(&mut reader, "{}:{}", u8, u8).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().offset, 3);
+        assert_eq!(
+            err.to_string(),
+            "invalid value at 1:4: expected u8, found 'x'"
+        );
+        assert_eq!(reader.as_str(), "12:xy");
+
+        let () = read_fmt!(reader, "12:").unwrap();
+
+        assert_eq!(reader.as_str(), "xy");
+    }
+}
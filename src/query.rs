@@ -0,0 +1,237 @@
+use core::iter::FusedIterator;
+
+use alloc::borrow::Cow;
+
+use crate::{ParseError, Position, StringReader};
+
+impl<'a> StringReader<'a> {
+    /// Get an iterator over key-value pairs of a query string (e.g.
+    /// `a=1&b=2`). Keys and values are percent-decoded and `+` is decoded as
+    /// a space by default. A pair without the key-value separator has an
+    /// empty value. Empty pairs are skipped. The pairs end at the end of the
+    /// input or at a closing character (see `QueryPairs::until()`). The
+    /// closing character is not consumed.
+    ///
+    /// The iterator stops after the first pair that cannot be decoded. The
+    /// reader will be positioned at the beginning of the pair in such case.
+    ///
+    /// # Arguments
+    ///
+    /// * `pair_sep` - separator of pairs (e.g. `&`)
+    /// * `kv_sep` - separator of keys and values (e.g. `=`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("q=caf%C3%A9+au+lait&&page=2&raw#top");
+    ///
+    /// let pairs = reader
+    ///     .query_pairs('&', '=')
+    ///     .until('#')
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(pairs[0], ("q".into(), "café au lait".into()));
+    /// assert_eq!(pairs[1], ("page".into(), "2".into()));
+    /// assert_eq!(pairs[2], ("raw".into(), "".into()));
+    /// assert_eq!(pairs.len(), 3);
+    /// assert_eq!(reader.as_str(), "#top");
+    /// ```
+    #[inline]
+    pub fn query_pairs(&mut self, pair_sep: char, kv_sep: char) -> QueryPairs<'_, 'a> {
+        QueryPairs {
+            reader: self,
+            pair_sep,
+            kv_sep,
+            close: None,
+            decode: true,
+            plus_as_space: true,
+            done: false,
+        }
+    }
+}
+
+/// Decoded key-value pair.
+type Pair<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+/// Iterator over key-value pairs of a query string.
+///
+/// The iterator is created by `StringReader::query_pairs()`.
+pub struct QueryPairs<'r, 'a> {
+    reader: &'r mut StringReader<'a>,
+    pair_sep: char,
+    kv_sep: char,
+    close: Option<char>,
+    decode: bool,
+    plus_as_space: bool,
+    done: bool,
+}
+
+impl<'a> QueryPairs<'_, 'a> {
+    /// Stop at a given closing character (e.g. `#`).
+    #[inline]
+    pub fn until(mut self, close: char) -> Self {
+        self.close = Some(close);
+        self
+    }
+
+    /// Enable/disable percent-decoding of keys and values (enabled by
+    /// default).
+    #[inline]
+    pub fn decode(mut self, enabled: bool) -> Self {
+        self.decode = enabled;
+        self
+    }
+
+    /// Enable/disable decoding of `+` as a space (enabled by default).
+    #[inline]
+    pub fn plus_as_space(mut self, enabled: bool) -> Self {
+        self.plus_as_space = enabled;
+        self
+    }
+
+    /// Read the next pair.
+    fn next_pair(&mut self) -> Option<Result<Pair<'a>, ParseError>> {
+        while self.reader.current_char() == Some(self.pair_sep) {
+            self.reader.skip_char();
+        }
+
+        let current = self.reader.current_char();
+
+        if current.is_none() || current == self.close {
+            return None;
+        }
+
+        let start = self.reader.checkpoint();
+
+        let pair_sep = self.pair_sep;
+        let kv_sep = self.kv_sep;
+        let close = self.close;
+
+        let key = self
+            .reader
+            .read_until(|c| c == pair_sep || c == kv_sep || Some(c) == close);
+
+        let mut value_start = self.reader.position();
+
+        let value = if self.reader.current_char() == Some(kv_sep) {
+            self.reader.skip_char();
+
+            value_start = self.reader.position();
+
+            self.reader
+                .read_until(|c| c == pair_sep || Some(c) == close)
+        } else {
+            ""
+        };
+
+        let res = self.decode_part(key, start.position()).and_then(|key| {
+            let value = self.decode_part(value, value_start)?;
+
+            Ok((key, value))
+        });
+
+        if res.is_err() {
+            self.reader.rewind(start);
+        }
+
+        Some(res)
+    }
+
+    /// Decode a given key or value starting at a given position.
+    fn decode_part(&self, part: &'a str, position: Position) -> Result<Cow<'a, str>, ParseError> {
+        if !self.plus_as_space || !part.contains('+') {
+            if !self.decode {
+                return Ok(Cow::Borrowed(part));
+            }
+
+            return self
+                .reader
+                .sub_reader(part, position)
+                .read_percent_decoded(|_| false);
+        }
+
+        // Replacing `+` does not change positions of other characters.
+        let part = part.replace('+', " ");
+
+        if !self.decode {
+            return Ok(Cow::Owned(part));
+        }
+
+        let decoded = StringReader::new(&part)
+            .with_position(position)
+            .read_percent_decoded(|_| false)?
+            .into_owned();
+
+        Ok(Cow::Owned(decoded))
+    }
+}
+
+impl<'a> Iterator for QueryPairs<'_, 'a> {
+    type Item = Result<Pair<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let res = self.next_pair();
+
+        if !matches!(res, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        res
+    }
+}
+
+impl FusedIterator for QueryPairs<'_, '_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_query_pairs() {
+        let mut reader = StringReader::new("&a=1+2%2B3&=x&b&c=%zz&d=4");
+
+        let mut pairs = reader.query_pairs('&', '=');
+
+        assert_eq!(pairs.next(), Some(Ok(("a".into(), "1 2+3".into()))));
+        assert_eq!(pairs.next(), Some(Ok(("".into(), "x".into()))));
+        assert_eq!(pairs.next(), Some(Ok(("b".into(), "".into()))));
+
+        let err = pairs.next().unwrap().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidEscape);
+        assert_eq!(err.position().offset, 18);
+
+        assert!(pairs.next().is_none());
+        assert_eq!(reader.as_str(), "c=%zz&d=4");
+
+        let mut reader = StringReader::new("k=a+b%20c;l=%41");
+
+        let pairs = reader
+            .query_pairs(';', '=')
+            .decode(false)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            pairs,
+            [("k".into(), "a b%20c".into()), ("l".into(), "%41".into())]
+        );
+
+        let mut reader = StringReader::new("k=a+b%20c");
+
+        let pairs = reader
+            .query_pairs('&', '=')
+            .plus_as_space(false)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(pairs, [("k".into(), "a+b c".into())]);
+    }
+}
@@ -0,0 +1,152 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{ParseError, Span, StringReader};
+
+/// Error reported during error recovery.
+///
+/// Diagnostics are produced by `StringReader::recover()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    /// The error.
+    pub error: ParseError,
+    /// Input skipped in order to resynchronize.
+    pub skipped: Span,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Skip input until a given condition is true or until the end of the
+    /// input and return the span of the skipped input. The character
+    /// satisfying the condition is not consumed. The method is meant for
+    /// resynchronization after an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `cnd` - a closure that takes a single character and returns
+    ///   true/false
+    pub fn recover_to<F>(&mut self, cnd: F) -> Span
+    where
+        F: FnMut(char) -> bool,
+    {
+        let start = self.position;
+
+        self.read_until(cnd);
+
+        Span {
+            start,
+            end: self.position,
+        }
+    }
+
+    /// Run a given parsing function and recover from its failure.
+    ///
+    /// If the function fails, the reader is rewound to its original state,
+    /// the input is skipped using `recover_to()`, a diagnostic is added to a
+    /// given collection and `None` is returned. It allows reporting all
+    /// problems in the input instead of stopping at the first one. Note that
+    /// the synchronization character is not consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostics` - collection of diagnostics (e.g. `Vec<Diagnostic>`)
+    /// * `sync` - a closure identifying the character where parsing can
+    ///   continue
+    /// * `f` - parsing function
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::{Diagnostic, StringReader};
+    ///
+    /// let mut reader = StringReader::new("x=1; y=?; z=3");
+    ///
+    /// let mut diagnostics = Vec::<Diagnostic>::new();
+    /// let mut values = Vec::new();
+    ///
+    /// while !reader.is_empty() {
+    ///     let value = reader.recover(&mut diagnostics, |c| c == ';', |r| {
+    ///         r.skip_whitespace();
+    ///         r.read_char()?;
+    ///         r.match_char('=')?;
+    ///         r.match_one_of("0123456789")
+    ///     });
+    ///
+    ///     values.extend(value);
+    ///
+    ///     let _ = reader.match_char(';');
+    /// }
+    ///
+    /// assert_eq!(values, ['1', '3']);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].skipped.range(), 4..8);
+    /// ```
+    pub fn recover<T, D, S, F>(&mut self, diagnostics: &mut D, sync: S, f: F) -> Option<T>
+    where
+        D: Extend<Diagnostic>,
+        S: FnMut(char) -> bool,
+        F: FnOnce(&mut Self) -> Result<T, ParseError>,
+    {
+        match self.transaction(f) {
+            Ok(res) => Some(res),
+            Err(error) => {
+                let skipped = self.recover_to(sync);
+
+                diagnostics.extend(Some(Diagnostic { error, skipped }));
+
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Diagnostic, ErrorKind, StringReader};
+
+    #[test]
+    fn test_recover() {
+        let mut reader = StringReader::new("1 2\n3 x 4\nfoo\n5 6");
+
+        let mut diagnostics = Vec::<Diagnostic>::new();
+        let mut sums = Vec::new();
+
+        while !reader.is_empty() {
+            let sum = reader.recover(
+                &mut diagnostics,
+                |c| c == '\n',
+                |r| {
+                    let a = r.read_word();
+                    let b = r.read_word();
+
+                    match (a.parse::<u32>(), b.parse::<u32>()) {
+                        (Ok(a), Ok(b)) if r.current_char() != Some(' ') => Ok(a + b),
+                        _ => r.match_char('\n').map(|_| 0),
+                    }
+                },
+            );
+
+            sums.extend(sum);
+
+            reader.skip_char();
+        }
+
+        assert_eq!(sums, [3, 11]);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].skipped.range(), 4..9);
+        assert_eq!(diagnostics[0].error.kind(), ErrorKind::NoMatch);
+        assert_eq!(diagnostics[1].skipped.start.line, 3);
+        assert_eq!(diagnostics[1].error.kind(), ErrorKind::NoMatch);
+
+        let mut reader = StringReader::new("abc");
+
+        let skipped = reader.recover_to(|c| c == ';');
+
+        assert_eq!(skipped.len(), 3);
+        assert!(reader.is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Semantic version (see <https://semver.org/>).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SemanticVersion<'a> {
+    /// Major version.
+    pub major: u64,
+    /// Minor version.
+    pub minor: u64,
+    /// Patch version.
+    pub patch: u64,
+    /// Pre-release identifiers (without the leading `-`, empty if missing).
+    pub pre: &'a str,
+    /// Build metadata (without the leading `+`, empty if missing).
+    pub build: &'a str,
+}
+
+impl Display for SemanticVersion<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read a semantic version (`MAJOR.MINOR.PATCH[-PRE][+BUILD]`) starting
+    /// at the current position.
+    ///
+    /// Numeric components cannot have leading zeros. Pre-release identifiers
+    /// and build metadata consist of dot-separated identifiers containing
+    /// ASCII letters, digits and hyphens. The method stops at the first
+    /// character that cannot be part of the version, i.e. `-` or `+` (and
+    /// `.` within the identifiers) is not consumed if it is not followed by
+    /// an identifier. The input won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("1.2.3-rc.1+build.5, ...");
+    ///
+    /// let version = reader.read_semver().unwrap();
+    ///
+    /// assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+    /// assert_eq!(version.pre, "rc.1");
+    /// assert_eq!(version.build, "build.5");
+    /// assert_eq!(reader.as_str(), ", ...");
+    /// ```
+    pub fn read_semver(&mut self) -> Result<SemanticVersion<'a>, ParseError> {
+        self.transaction(|reader| {
+            let major = reader.read_version_number()?;
+
+            reader.match_char('.')?;
+
+            let minor = reader.read_version_number()?;
+
+            reader.match_char('.')?;
+
+            let patch = reader.read_version_number()?;
+
+            let pre = reader.read_version_identifiers('-', true)?;
+            let build = reader.read_version_identifiers('+', false)?;
+
+            let res = SemanticVersion {
+                major,
+                minor,
+                patch,
+                pre,
+                build,
+            };
+
+            Ok(res)
+        })
+    }
+
+    /// Read a numeric version component.
+    fn read_version_number(&mut self) -> Result<u64, ParseError> {
+        let position = self.position;
+
+        let digits = self.read_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            let kind = if self.is_empty() {
+                ErrorKind::EmptyInput
+            } else {
+                ErrorKind::NoMatch
+            };
+
+            let err = self
+                .error(kind)
+                .with_expected(Expected::Description("version number"))
+                .with_found(self.current_char());
+
+            return Err(err);
+        } else if has_leading_zero(digits) {
+            let err = ParseError::new(ErrorKind::InvalidValue, position)
+                .with_expected(Expected::Description("version number"))
+                .with_found(Some('0'));
+
+            return Err(err);
+        }
+
+        crate::parse::parse_value(digits, position, "version number")
+    }
+
+    /// Read dot-separated version identifiers following a given prefix. An
+    /// empty string is returned if the input does not start with the prefix
+    /// followed by an identifier.
+    fn read_version_identifiers(
+        &mut self,
+        prefix: char,
+        check_numeric: bool,
+    ) -> Result<&'a str, ParseError> {
+        let input = self.as_str();
+
+        let identifiers = match input.strip_prefix(prefix) {
+            Some(rest) => rest,
+            None => return Ok(""),
+        };
+
+        let mut len = 0;
+
+        for identifier in identifiers.split('.') {
+            let valid = identifier
+                .bytes()
+                .take_while(|&b| b.is_ascii_alphanumeric() || b == b'-')
+                .count();
+
+            if valid == 0 {
+                break;
+            }
+
+            let identifier = &identifier[..valid];
+
+            let is_numeric = identifier.bytes().all(|b| b.is_ascii_digit());
+
+            if check_numeric && is_numeric && has_leading_zero(identifier) {
+                let position = self.position_of(&identifiers[len..]);
+
+                let err = ParseError::new(ErrorKind::InvalidValue, position)
+                    .with_expected(Expected::Description("pre-release identifier"))
+                    .with_found(Some('0'));
+
+                return Err(err);
+            }
+
+            len += valid;
+
+            if identifiers[len..].starts_with('.') {
+                len += 1;
+            } else {
+                break;
+            }
+        }
+
+        // do not consume the trailing dot (if any)
+        let identifiers = identifiers[..len].trim_end_matches('.');
+
+        if identifiers.is_empty() {
+            return Ok("");
+        }
+
+        self.split_to(identifiers.len() + prefix.len_utf8());
+
+        Ok(identifiers)
+    }
+}
+
+/// Check if a given number has a leading zero.
+fn has_leading_zero(digits: &str) -> bool {
+    digits.len() > 1 && digits.starts_with('0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SemanticVersion;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_semver() {
+        let mut reader =
+            StringReader::new("v10.0.1 1.0.0-alpha-1.0.x.+ 2.0.0+2024.build- 1.2.3-rc.");
+
+        reader.skip_char();
+
+        assert_eq!(
+            reader.read_semver(),
+            Ok(SemanticVersion {
+                major: 10,
+                minor: 0,
+                patch: 1,
+                pre: "",
+                build: "",
+            })
+        );
+
+        reader.skip_whitespace();
+
+        let version = reader.read_semver().unwrap();
+
+        assert_eq!(version.pre, "alpha-1.0.x");
+        assert_eq!(version.build, "");
+        assert_eq!(version.to_string(), "1.0.0-alpha-1.0.x");
+        assert_eq!(reader.as_str(), ".+ 2.0.0+2024.build- 1.2.3-rc.");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        let version = reader.read_semver().unwrap();
+
+        assert_eq!(version.build, "2024.build-");
+        assert_eq!(version.to_string(), "2.0.0+2024.build-");
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_semver().unwrap().pre, "rc");
+        assert_eq!(reader.as_str(), ".");
+
+        for input in &[
+            "01.2.3",
+            "1.2",
+            "1.2.3-01",
+            "1.2.x",
+            "99999999999999999999.0.0",
+        ] {
+            let mut reader = StringReader::new(input);
+
+            assert!(reader.read_semver().is_err());
+            assert_eq!(reader.as_str(), *input);
+        }
+
+        let err = StringReader::new("1.2.3-rc.01").read_semver().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 10);
+    }
+}
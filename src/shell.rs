@@ -0,0 +1,167 @@
+use alloc::borrow::Cow;
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Characters that can be escaped within double quotes.
+const DOUBLE_QUOTE_ESCAPES: &str = "$`\"\\\n";
+
+impl<'a> StringReader<'a> {
+    /// Read one word using the splitting rules of the POSIX shell. Leading
+    /// whitespace is skipped.
+    ///
+    /// The word ends with the first unquoted whitespace character or with the
+    /// end of the input. Characters enclosed in single quotes are taken
+    /// literally. Within double quotes, only a backslash followed by `$`,
+    /// `` ` ``, `"`, `\` or a line feed is an escape sequence. Outside of
+    /// quotes, a backslash escapes any character. An escaped line feed is
+    /// removed. Note that no expansions are performed. The returned string
+    /// borrows the input if the word does not need to be modified. The input
+    /// won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new(r#"grep -e 'a b' "c \"d\""\ e"#);
+    ///
+    /// let mut words = Vec::new();
+    ///
+    /// while !reader.as_str().trim().is_empty() {
+    ///     words.push(reader.read_shell_word().unwrap());
+    /// }
+    ///
+    /// assert_eq!(words, ["grep", "-e", "a b", "c \"d\" e"]);
+    /// ```
+    pub fn read_shell_word(&mut self) -> Result<Cow<'a, str>, ParseError> {
+        self.transaction(|reader| {
+            reader.skip_whitespace();
+
+            if reader.is_empty() {
+                let err = reader
+                    .error(ErrorKind::EmptyInput)
+                    .with_expected(Expected::Description("word"));
+
+                return Err(err);
+            }
+
+            let mut res = Cow::Borrowed("");
+
+            while let Some(c) = reader.current_char() {
+                let start = reader.position;
+
+                match c {
+                    '\'' => {
+                        reader.skip_char();
+
+                        push_str(&mut res, reader.read_until_char('\''));
+
+                        reader.match_char('\'')?;
+                    }
+                    '"' => {
+                        reader.skip_char();
+
+                        loop {
+                            push_str(&mut res, reader.read_until(|c| c == '"' || c == '\\'));
+
+                            match reader.current_char() {
+                                Some('"') => break,
+                                Some('\\') => {
+                                    reader.skip_char();
+
+                                    match reader.current_char() {
+                                        Some('\n') => reader.skip_char(),
+                                        Some(c) if DOUBLE_QUOTE_ESCAPES.contains(c) => {
+                                            reader.skip_char();
+
+                                            res.to_mut().push(c);
+                                        }
+                                        _ => res.to_mut().push('\\'),
+                                    }
+                                }
+                                _ => break,
+                            }
+                        }
+
+                        reader.match_char('"')?;
+                    }
+                    '\\' => {
+                        reader.skip_char();
+
+                        match reader.read_char() {
+                            Ok('\n') => (),
+                            Ok(c) => res.to_mut().push(c),
+                            Err(_) => {
+                                let err = ParseError::new(ErrorKind::InvalidEscape, start)
+                                    .with_found(Some('\\'));
+
+                                return Err(err);
+                            }
+                        }
+                    }
+                    c if c.is_whitespace() => break,
+                    _ => {
+                        let s = reader.read_until(|c| {
+                            c.is_whitespace() || c == '\'' || c == '"' || c == '\\'
+                        });
+
+                        push_str(&mut res, s);
+                    }
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Append a given string to a given result, borrowing the string if the
+/// result is empty.
+fn push_str<'a>(res: &mut Cow<'a, str>, s: &'a str) {
+    if res.is_empty() {
+        *res = Cow::Borrowed(s);
+    } else if !s.is_empty() {
+        res.to_mut().push_str(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_shell_word() {
+        let input = r#"  foo 'a b' "x\$y\z"'q' a\ b\
+c '' "" 'unterminated"#;
+
+        let mut reader = StringReader::new(input);
+
+        assert!(matches!(reader.read_shell_word(), Ok(Cow::Borrowed("foo"))));
+        assert!(matches!(reader.read_shell_word(), Ok(Cow::Borrowed("a b"))));
+        assert_eq!(reader.read_shell_word().unwrap(), "x$y\\zq");
+        assert_eq!(reader.read_shell_word().unwrap(), "a bc");
+        assert_eq!(reader.read_shell_word().unwrap(), "");
+        assert_eq!(reader.read_shell_word().unwrap(), "");
+
+        let err = reader.read_shell_word().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(reader.as_str(), " 'unterminated");
+
+        reader.read_word();
+
+        assert_eq!(
+            reader.read_shell_word().unwrap_err().kind(),
+            ErrorKind::EmptyInput
+        );
+
+        let mut reader = StringReader::new("foo\\");
+
+        assert_eq!(
+            reader.read_shell_word().unwrap_err().kind(),
+            ErrorKind::InvalidEscape
+        );
+    }
+}
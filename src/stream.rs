@@ -0,0 +1,594 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, BufRead},
+    result,
+    str::{self, FromStr},
+};
+
+#[cfg(feature = "async")]
+use std::{future, pin::Pin, task::Poll};
+
+#[cfg(feature = "async")]
+use futures_io::AsyncBufRead;
+
+use crate::{ErrorKind, Expected, ParseError, Position, StringReader};
+
+/// Streaming reader error.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The input could not be parsed or more input is needed (see
+    /// `ErrorKind::Incomplete`).
+    Parse(ParseError),
+    /// IO error.
+    Io(io::Error),
+}
+
+impl StreamError {
+    /// Check if the error was caused by insufficient input. The operation
+    /// can be retried once more input is available.
+    #[inline]
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, Self::Parse(err) if err.kind() == ErrorKind::Incomplete)
+    }
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter) -> result::Result<(), fmt::Error> {
+        match self {
+            Self::Parse(err) => Display::fmt(err, f),
+            Self::Io(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            Self::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for StreamError {
+    #[inline]
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<io::Error> for StreamError {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Generate the reading methods. The methods are asynchronous if the
+/// `async` keyword is given.
+macro_rules! impl_stream_methods {
+    ($($async:ident)?; $($await:tt)*) => {
+        /// Get the current position.
+        #[inline]
+        pub fn position(&self) -> Position {
+            self.buffer.position
+        }
+
+        /// Get the current character (if any) without advancing the input.
+        pub $($async)? fn current_char(&mut self) -> Result<Option<char>, StreamError> {
+            self.fill_while(|s| s.is_empty())$($await)*?;
+
+            Ok(self.buffer.available().chars().next())
+        }
+
+        /// Check if there is no more input.
+        #[inline]
+        pub $($async)? fn is_empty(&mut self) -> Result<bool, StreamError> {
+            Ok(self.current_char()$($await)*?.is_none())
+        }
+
+        /// Read the next character.
+        pub $($async)? fn read_char(&mut self) -> Result<char, StreamError> {
+            self.fill_while(|s| s.is_empty())$($await)*?;
+            self.buffer.run(|r| r.read_char())
+        }
+
+        /// Match the next character to a given one. The input won't be
+        /// advanced in case of an error.
+        pub $($async)? fn match_char(&mut self, expected: char) -> Result<(), StreamError> {
+            self.fill_while(|s| s.is_empty())$($await)*?;
+            self.buffer.run(|r| r.match_char(expected))
+        }
+
+        /// Match a given string to the input. The input won't be advanced in
+        /// case of an error.
+        pub $($async)? fn match_str(&mut self, val: &str) -> Result<(), StreamError> {
+            self.fill_while(|s| s.len() < val.len() && val.starts_with(s))$($await)*?;
+            self.buffer.run(|r| r.match_str(val))
+        }
+
+        /// Skip all whitespace characters.
+        pub $($async)? fn skip_whitespace(&mut self) -> Result<(), StreamError> {
+            self.fill_while(|s| s.trim_start().is_empty())$($await)*?;
+            self.buffer.run(|r| {
+                r.skip_whitespace();
+
+                Ok(())
+            })
+        }
+
+        /// Read until a given condition is true or until the end of the
+        /// input.
+        pub $($async)? fn read_until<F>(&mut self, mut cnd: F) -> Result<&str, StreamError>
+        where
+            F: FnMut(char) -> bool,
+        {
+            self.fill_while(|s| !s.contains(&mut cnd))$($await)*?;
+            self.buffer.run(|r| Ok(r.read_until(cnd)))
+        }
+
+        /// Read the rest of the current line and return it. See
+        /// `StringReader::read_line()` for more info.
+        pub $($async)? fn read_line(&mut self) -> Result<&str, StreamError> {
+            self.fill_while(|s| !s.contains('\n'))$($await)*?;
+            self.buffer.run(|r| Ok(r.read_line()))
+        }
+
+        /// Read the next word. Words are delimited by whitespace. The method
+        /// skips all initial whitespace (if any).
+        pub $($async)? fn read_word(&mut self) -> Result<&str, StreamError> {
+            self.fill_while(|s| !s.trim_start().contains(char::is_whitespace))$($await)*?;
+            self.buffer.run(|r| Ok(r.read_word()))
+        }
+
+        /// Read the next word and parse it. The input won't be advanced if
+        /// the word cannot be parsed.
+        pub $($async)? fn parse_word<T>(&mut self) -> Result<T, StreamError>
+        where
+            T: FromStr,
+        {
+            self.fill_while(|s| !s.trim_start().contains(char::is_whitespace))$($await)*?;
+            self.buffer.run(|r| {
+                r.skip_whitespace();
+
+                let position = r.position();
+
+                crate::parse::parse_value(r.read_word(), position, "word")
+            })
+        }
+
+        /// Get a reference to the underlying reader.
+        #[inline]
+        pub fn get_ref(&self) -> &R {
+            &self.inner
+        }
+
+        /// Take the underlying reader. Any buffered input is lost.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+
+        /// Pull more data from the underlying reader while a given condition
+        /// is true for the buffered input and the end of the input has not
+        /// been reached.
+        $($async)? fn fill_while<F>(&mut self, mut cnd: F) -> Result<(), StreamError>
+        where
+            F: FnMut(&str) -> bool,
+        {
+            while !self.buffer.eof && cnd(self.buffer.available()) {
+                self.fill()$($await)*?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+/// Streaming reader.
+///
+/// The reader provides the basic `StringReader` primitives on top of a
+/// `BufRead` implementation. More data is pulled from the underlying reader
+/// on demand, so that tokens split across buffer boundaries are handled
+/// transparently. If the underlying reader would block, the operation fails
+/// with an `ErrorKind::Incomplete` error and the reader is left unchanged,
+/// so the operation can be retried later. Words are delimited by Unicode
+/// whitespace.
+///
+/// # Example
+///
+/// ```
+/// use std::io::BufReader;
+///
+/// use str_reader::StreamingReader;
+///
+/// let input = BufReader::with_capacity(4, "HTTP/1.1 404 Not Found\r\n".as_bytes());
+///
+/// let mut reader = StreamingReader::new(input);
+///
+/// reader.match_str("HTTP/").unwrap();
+///
+/// assert_eq!(reader.read_word().unwrap(), "1.1");
+/// assert_eq!(reader.parse_word::<u16>().unwrap(), 404);
+/// assert_eq!(reader.read_line().unwrap(), " Not Found");
+/// assert!(reader.is_empty().unwrap());
+/// ```
+pub struct StreamingReader<R> {
+    inner: R,
+    buffer: StreamBuffer,
+}
+
+impl<R> StreamingReader<R>
+where
+    R: BufRead,
+{
+    /// Create a new streaming reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - underlying reader
+    #[inline]
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buffer: StreamBuffer::new(),
+        }
+    }
+
+    impl_stream_methods!(;);
+
+    /// Pull more data from the underlying reader.
+    fn fill(&mut self) -> Result<(), StreamError> {
+        let data = loop {
+            match self.inner.fill_buf() {
+                Ok(data) => break data,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    return Err(self.buffer.error(ErrorKind::Incomplete).into());
+                }
+                Err(err) => return Err(err.into()),
+            }
+        };
+
+        let len = data.len();
+
+        let res = self.buffer.push(data);
+
+        self.inner.consume(len);
+
+        res.map_err(StreamError::from)
+    }
+}
+
+/// Asynchronous streaming reader.
+///
+/// The reader is an asynchronous counterpart of `StreamingReader` working on
+/// top of a `futures_io::AsyncBufRead` implementation.
+///
+/// # Example
+///
+/// ```
+/// # futures_executor::block_on(async {
+/// use str_reader::AsyncStreamingReader;
+///
+/// let mut reader = AsyncStreamingReader::new("PING 42\n".as_bytes());
+///
+/// reader.match_str("PING").await.unwrap();
+///
+/// assert_eq!(reader.parse_word::<u32>().await.unwrap(), 42);
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub struct AsyncStreamingReader<R> {
+    inner: R,
+    buffer: StreamBuffer,
+}
+
+#[cfg(feature = "async")]
+impl<R> AsyncStreamingReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Create a new asynchronous streaming reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - underlying reader
+    #[inline]
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buffer: StreamBuffer::new(),
+        }
+    }
+
+    impl_stream_methods!(async; .await);
+
+    /// Pull more data from the underlying reader.
+    async fn fill(&mut self) -> Result<(), StreamError> {
+        future::poll_fn(|cx| {
+            let data = match Pin::new(&mut self.inner).poll_fill_buf(cx) {
+                Poll::Ready(Ok(data)) => data,
+                Poll::Ready(Err(err)) if err.kind() == io::ErrorKind::Interrupted => {
+                    cx.waker().wake_by_ref();
+
+                    return Poll::Pending;
+                }
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err.into())),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let len = data.len();
+
+            let res = self.buffer.push(data);
+
+            Pin::new(&mut self.inner).consume(len);
+
+            Poll::Ready(res.map_err(StreamError::from))
+        })
+        .await
+    }
+}
+
+/// Buffered input of a streaming reader.
+struct StreamBuffer {
+    buffer: String,
+    offset: usize,
+    partial: Vec<u8>,
+    position: Position,
+    eof: bool,
+}
+
+impl StreamBuffer {
+    /// Create a new empty buffer.
+    fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            offset: 0,
+            partial: Vec::new(),
+            position: Position::START,
+            eof: false,
+        }
+    }
+
+    /// Get the buffered input.
+    #[inline]
+    fn available(&self) -> &str {
+        &self.buffer[self.offset..]
+    }
+
+    /// Run a given operation on the buffered input and advance the input if
+    /// the operation succeeds.
+    fn run<'a, F, T>(&'a mut self, f: F) -> Result<T, StreamError>
+    where
+        F: FnOnce(&mut StringReader<'a>) -> Result<T, ParseError>,
+    {
+        let input = &self.buffer[self.offset..];
+
+        let mut reader = StringReader::new(input).with_position(self.position);
+
+        let res = f(&mut reader)?;
+
+        self.offset += input.len() - reader.as_str().len();
+        self.position = reader.position();
+
+        Ok(res)
+    }
+
+    /// Append given data to the buffer. Empty data mean the end of the
+    /// input.
+    fn push(&mut self, data: &[u8]) -> Result<(), ParseError> {
+        // Drop the consumed part of the buffer.
+        self.buffer.drain(..self.offset);
+        self.offset = 0;
+
+        if data.is_empty() {
+            self.eof = true;
+        }
+
+        self.partial.extend_from_slice(data);
+
+        let valid = match str::from_utf8(&self.partial) {
+            Ok(s) => s,
+            Err(err) if err.error_len().is_none() && !self.eof => {
+                // The last character is not complete yet.
+                str::from_utf8(&self.partial[..err.valid_up_to()]).unwrap_or("")
+            }
+            Err(err) => {
+                let mut position = self.position;
+
+                position.advance_str(&self.buffer);
+                position.advance_bytes(&self.partial[..err.valid_up_to()]);
+
+                let err = ParseError::new(ErrorKind::InvalidUtf8, position)
+                    .with_expected(Expected::Description("UTF-8"))
+                    .with_found_byte(self.partial.get(err.valid_up_to()).copied());
+
+                return Err(err);
+            }
+        };
+
+        let valid_len = valid.len();
+
+        self.buffer.push_str(valid);
+        self.partial.drain(..valid_len);
+
+        Ok(())
+    }
+
+    /// Create an error of a given kind at the current position.
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError::new(kind, self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::StreamingReader;
+
+    use crate::{ErrorKind, Expected};
+
+    /// Reader returning given chunks and `WouldBlock` in between.
+    struct Chunks(Vec<&'static [u8]>, bool);
+
+    impl Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.1 = !self.1;
+
+            if self.1 {
+                return Err(io::ErrorKind::WouldBlock.into());
+            } else if self.0.is_empty() {
+                return Ok(0);
+            }
+
+            let chunk = self.0.remove(0);
+
+            buf[..chunk.len()].copy_from_slice(chunk);
+
+            Ok(chunk.len())
+        }
+    }
+
+    #[test]
+    fn test_streaming_reader() {
+        let input = BufReader::with_capacity(3, "a=1\r\n čauky 42x ".as_bytes());
+
+        let mut reader = StreamingReader::new(input);
+
+        assert_eq!(reader.read_until(|c| c == '=').unwrap(), "a");
+
+        reader.match_char('=').unwrap();
+
+        assert_eq!(reader.read_line().unwrap(), "1");
+
+        let err = reader.match_str(" čaut").unwrap_err();
+
+        assert!(matches!(err, super::StreamError::Parse(err) if err.position().column == 5));
+
+        reader.skip_whitespace().unwrap();
+
+        assert_eq!(reader.read_word().unwrap(), "čauky");
+        assert_eq!(reader.position().column, 7);
+
+        let err = reader.parse_word::<u32>().unwrap_err();
+
+        assert!(!err.is_incomplete());
+
+        assert_eq!(reader.read_word().unwrap(), "42x");
+        assert_eq!(reader.read_char().unwrap(), ' ');
+        assert!(reader.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_incomplete() {
+        let input = BufReader::new(Chunks(vec![b"12", b"3 \xc4", b"\x8d\xff"], false));
+
+        let mut reader = StreamingReader::new(input);
+
+        let mut attempts = 0;
+
+        let n = loop {
+            attempts += 1;
+
+            match reader.parse_word::<u32>() {
+                Ok(n) => break n,
+                Err(err) if err.is_incomplete() => continue,
+                Err(err) => panic!("unexpected error: {}", err),
+            }
+        };
+
+        assert_eq!(n, 123);
+        assert_eq!(attempts, 3);
+
+        let err = loop {
+            match reader.read_word() {
+                Err(err) if err.is_incomplete() => continue,
+                res => break res.unwrap_err(),
+            }
+        };
+
+        match err {
+            super::StreamError::Parse(err) => {
+                assert_eq!(err.kind(), ErrorKind::InvalidUtf8);
+                assert_eq!(err.position().offset, 6);
+                assert_eq!(err.expected(), Some(&Expected::Description("UTF-8")));
+                assert_eq!(err.found_byte(), Some(0xff));
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_streaming_reader() {
+        use std::{
+            pin::Pin,
+            task::{Context, Poll},
+        };
+
+        use futures_io::{AsyncBufRead, AsyncRead};
+
+        use super::AsyncStreamingReader;
+
+        /// Reader returning given chunks and `Pending` in between.
+        struct AsyncChunks(Vec<&'static [u8]>, bool);
+
+        impl AsyncRead for AsyncChunks {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                _: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                // only the buffered interface is used by the reader
+                Poll::Ready(Ok(0))
+            }
+        }
+
+        impl AsyncBufRead for AsyncChunks {
+            fn poll_fill_buf(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<io::Result<&[u8]>> {
+                let this = self.get_mut();
+
+                this.1 = !this.1;
+
+                if this.1 {
+                    cx.waker().wake_by_ref();
+
+                    Poll::Pending
+                } else {
+                    Poll::Ready(Ok(this.0.first().copied().unwrap_or(b"")))
+                }
+            }
+
+            fn consume(self: Pin<&mut Self>, amt: usize) {
+                let this = self.get_mut();
+
+                if amt > 0 {
+                    assert_eq!(this.0.remove(0).len(), amt);
+                }
+            }
+        }
+
+        futures_executor::block_on(async {
+            let input = AsyncChunks(vec![b"GE", b"T /\xc3", b"\xa9 HT", b"TP/1.1\r\n"], false);
+
+            let mut reader = AsyncStreamingReader::new(input);
+
+            reader.match_str("GET").await.unwrap();
+
+            assert_eq!(reader.read_word().await.unwrap(), "/é");
+            assert!(reader.match_str(" HTTP/2").await.is_err());
+            assert_eq!(reader.position().column, 7);
+
+            reader.match_str(" HTTP/").await.unwrap();
+
+            assert_eq!(reader.parse_word::<f32>().await.unwrap(), 1.1);
+            assert_eq!(reader.read_line().await.unwrap(), "");
+            assert!(reader.is_empty().await.unwrap());
+        });
+    }
+}
@@ -0,0 +1,524 @@
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{ErrorKind, Expected, ParseError, Position, StringReader};
+
+/// Abbreviated day names.
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Full day names.
+const LONG_DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Abbreviated month names.
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Broken-down UTC date and time.
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+}
+
+impl DateTime {
+    /// Convert the date and time into a system time.
+    fn to_system_time(&self, offset: i64) -> Option<SystemTime> {
+        // leap seconds are accepted and they roll over to the next minute
+        let valid = (1..=12).contains(&self.month)
+            && (1..=days_in_month(self.year, self.month)).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second <= 60;
+
+        if !valid {
+            return None;
+        }
+
+        let days = days_from_civil(self.year, self.month, self.day);
+
+        let secs = days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - offset;
+
+        let time = if secs < 0 {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+        } else {
+            UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64))?
+        };
+
+        time.checked_add(Duration::from_nanos(u64::from(self.nanos)))
+    }
+}
+
+impl<'a> StringReader<'a> {
+    /// Read an RFC 3339 timestamp (e.g. `2024-02-29T12:30:00.5+01:00`)
+    /// starting at the current position.
+    ///
+    /// The date and time may be separated by `T`, `t` or a space. Fractional
+    /// seconds are optional and digits beyond nanosecond precision are
+    /// truncated. The UTC offset is either `Z` (or `z`) or `+HH:MM`/`-HH:MM`.
+    /// The input won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("1970-01-01T01:00:01+01:00 started");
+    ///
+    /// assert_eq!(reader.read_rfc3339(), Ok(UNIX_EPOCH + Duration::from_secs(1)));
+    /// assert_eq!(reader.as_str(), " started");
+    /// ```
+    pub fn read_rfc3339(&mut self) -> Result<SystemTime, ParseError> {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let year = reader.read_fixed_digits(4)?;
+
+            reader.match_char('-')?;
+
+            let month = reader.read_fixed_digits(2)?;
+
+            reader.match_char('-')?;
+
+            let day = reader.read_fixed_digits(2)?;
+
+            reader.match_one_of("Tt ")?;
+
+            let (hour, minute, second) = reader.read_time_of_day()?;
+
+            let mut nanos = 0;
+
+            if reader.starts_with(".") {
+                reader.skip_char();
+
+                let digits = reader.read_while(|c| c.is_ascii_digit());
+
+                if digits.is_empty() {
+                    return Err(reader.expected("fractional seconds"));
+                }
+
+                nanos = digits
+                    .bytes()
+                    .chain(core::iter::repeat(b'0'))
+                    .take(9)
+                    .fold(0, |res, b| res * 10 + u32::from(b - b'0'));
+            }
+
+            let offset = match reader.match_one_of("Zz+-")? {
+                'Z' | 'z' => 0,
+                sign => {
+                    let position = reader.position;
+
+                    let hours = reader.read_fixed_digits(2)?;
+
+                    reader.match_char(':')?;
+
+                    let minutes = reader.read_fixed_digits(2)?;
+
+                    if hours > 23 || minutes > 59 {
+                        return Err(invalid(position, "UTC offset"));
+                    }
+
+                    let offset = i64::from(hours * 3600 + minutes * 60);
+
+                    if sign == '-' {
+                        -offset
+                    } else {
+                        offset
+                    }
+                }
+            };
+
+            let date_time = DateTime {
+                year: i64::from(year),
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                nanos,
+            };
+
+            date_time
+                .to_system_time(offset)
+                .ok_or_else(|| invalid(start, "RFC 3339 timestamp"))
+        })
+    }
+
+    /// Read an HTTP date starting at the current position.
+    ///
+    /// The preferred format (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`) is
+    /// accepted as well as the obsolete RFC 850 format (e.g.
+    /// `Sunday, 06-Nov-94 08:49:37 GMT`) and the ANSI C `asctime()` format
+    /// (e.g. `Sun Nov  6 08:49:37 1994`). Two-digit years below 70 are
+    /// interpreted as years 2000 to 2069. Day names are not checked against
+    /// the date. The input won't be advanced in case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("Sun, 06 Nov 1994 08:49:37 GMT");
+    ///
+    /// assert_eq!(
+    ///     reader.read_http_date(),
+    ///     Ok(UNIX_EPOCH + Duration::from_secs(784111777))
+    /// );
+    /// ```
+    pub fn read_http_date(&mut self) -> Result<SystemTime, ParseError> {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let day_name = reader.read_while(|c| c.is_ascii_alphabetic());
+
+            let date_time = if DAY_NAMES.contains(&day_name) && reader.starts_with(",") {
+                reader.match_str(", ")?;
+
+                let day = reader.read_fixed_digits(2)?;
+
+                reader.match_char(' ')?;
+
+                let month = reader.read_month()?;
+
+                reader.match_char(' ')?;
+
+                let year = reader.read_fixed_digits(4)?;
+
+                reader.match_char(' ')?;
+
+                let (hour, minute, second) = reader.read_time_of_day()?;
+
+                reader.match_str(" GMT")?;
+
+                DateTime {
+                    year: i64::from(year),
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    nanos: 0,
+                }
+            } else if LONG_DAY_NAMES.contains(&day_name) {
+                reader.match_str(", ")?;
+
+                let day = reader.read_fixed_digits(2)?;
+
+                reader.match_char('-')?;
+
+                let month = reader.read_month()?;
+
+                reader.match_char('-')?;
+
+                let year = reader.read_fixed_digits(2)?;
+
+                reader.match_char(' ')?;
+
+                let (hour, minute, second) = reader.read_time_of_day()?;
+
+                reader.match_str(" GMT")?;
+
+                let century = if year < 70 { 2000 } else { 1900 };
+
+                DateTime {
+                    year: i64::from(century + year),
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    nanos: 0,
+                }
+            } else if DAY_NAMES.contains(&day_name) {
+                reader.match_char(' ')?;
+
+                let month = reader.read_month()?;
+
+                reader.match_char(' ')?;
+
+                let day = if reader.starts_with(" ") {
+                    reader.skip_char();
+                    reader.read_fixed_digits(1)?
+                } else {
+                    reader.read_fixed_digits(2)?
+                };
+
+                reader.match_char(' ')?;
+
+                let (hour, minute, second) = reader.read_time_of_day()?;
+
+                reader.match_char(' ')?;
+
+                let year = reader.read_fixed_digits(4)?;
+
+                DateTime {
+                    year: i64::from(year),
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    nanos: 0,
+                }
+            } else {
+                let err = ParseError::new(ErrorKind::NoMatch, start)
+                    .with_expected(Expected::Description("day name"))
+                    .with_found(day_name.chars().next().or_else(|| reader.current_char()));
+
+                return Err(err);
+            };
+
+            // leap seconds are not allowed in HTTP dates
+            if date_time.second > 59 {
+                return Err(invalid(start, "HTTP date"));
+            }
+
+            date_time
+                .to_system_time(0)
+                .ok_or_else(|| invalid(start, "HTTP date"))
+        })
+    }
+
+    /// Read a UNIX timestamp (i.e. the number of seconds since the UNIX
+    /// epoch) with an optional fractional part starting at the current
+    /// position. Digits beyond nanosecond precision are truncated. The input
+    /// won't be advanced in case of an error.
+    pub fn read_unix_timestamp(&mut self) -> Result<SystemTime, ParseError> {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let (secs, fraction) = reader.read_decimal("UNIX timestamp")?;
+
+            u64::try_from(secs)
+                .ok()
+                .and_then(|secs| UNIX_EPOCH.checked_add(Duration::from_secs(secs)))
+                .and_then(|time| {
+                    let nanos = fraction.mul(1_000_000_000) as u64;
+
+                    time.checked_add(Duration::from_nanos(nanos))
+                })
+                .ok_or_else(|| invalid(start, "UNIX timestamp"))
+        })
+    }
+
+    /// Read time of day in the `HH:MM:SS` format.
+    fn read_time_of_day(&mut self) -> Result<(u32, u32, u32), ParseError> {
+        let hour = self.read_fixed_digits(2)?;
+
+        self.match_char(':')?;
+
+        let minute = self.read_fixed_digits(2)?;
+
+        self.match_char(':')?;
+
+        let second = self.read_fixed_digits(2)?;
+
+        Ok((hour, minute, second))
+    }
+
+    /// Read an abbreviated month name and return the month number.
+    fn read_month(&mut self) -> Result<u32, ParseError> {
+        let name = self.peek_str(3);
+
+        match MONTH_NAMES.iter().position(|&month| month == name) {
+            Some(index) => {
+                self.split_to(name.len());
+
+                Ok(index as u32 + 1)
+            }
+            None => Err(self.expected("month name")),
+        }
+    }
+
+    /// Read exactly a given number of decimal digits.
+    fn read_fixed_digits(&mut self, n: usize) -> Result<u32, ParseError> {
+        let digits = self.peek_str(n);
+
+        if digits.len() < n || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.expected("digit"));
+        }
+
+        self.split_to(n);
+
+        Ok(digits
+            .bytes()
+            .fold(0, |res, b| res * 10 + u32::from(b - b'0')))
+    }
+
+    /// Create an error for an unexpected input at the current position.
+    fn expected(&self, expected: &'static str) -> ParseError {
+        let c = self.current_char();
+
+        let kind = if c.is_some() {
+            ErrorKind::NoMatch
+        } else {
+            ErrorKind::EmptyInput
+        };
+
+        self.error(kind)
+            .with_expected(Expected::Description(expected))
+            .with_found(c)
+    }
+}
+
+/// Create an error for an invalid value at a given position.
+fn invalid(position: Position, expected: &'static str) -> ParseError {
+    ParseError::new(ErrorKind::InvalidValue, position)
+        .with_expected(Expected::Description(expected))
+}
+
+/// Check if a given year is a leap year.
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Get the number of days in a given month.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Get the number of days since the UNIX epoch for a given date in the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+
+    let year_of_era = year - era * 400;
+
+    // days since March 1
+    let day_of_year = (153 * i64::from((month + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_rfc3339() {
+        let mut reader = StringReader::new(
+            "2024-02-29T12:30:00.25Z 1969-12-31t23:59:59-00:30 2023-02-29 00:00:00Z",
+        );
+
+        let time = reader.read_rfc3339().unwrap();
+
+        assert_eq!(
+            time.duration_since(UNIX_EPOCH).unwrap(),
+            Duration::from_millis(1_709_209_800_250)
+        );
+
+        reader.skip_whitespace();
+
+        assert_eq!(
+            reader.read_rfc3339(),
+            Ok(UNIX_EPOCH + Duration::from_secs(1799))
+        );
+
+        reader.skip_whitespace();
+
+        let err = reader.read_rfc3339().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(err.position().column, 51);
+
+        let mut reader = StringReader::new("2000-01-01T00:00:00 x");
+
+        assert_eq!(
+            reader.read_rfc3339().unwrap_err().kind(),
+            ErrorKind::NoMatch
+        );
+        assert_eq!(reader.as_str(), "2000-01-01T00:00:00 x");
+    }
+
+    #[test]
+    fn test_http_date() {
+        let expected = UNIX_EPOCH + Duration::from_secs(784_111_777);
+
+        for input in &[
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            let mut reader = StringReader::new(input);
+
+            assert_eq!(reader.read_http_date(), Ok(expected));
+            assert!(reader.is_empty());
+        }
+
+        let mut reader = StringReader::new("Sunday, 01-Jan-05 00:00:00 GMT;");
+
+        assert_eq!(
+            reader.read_http_date(),
+            Ok(UNIX_EPOCH + Duration::from_secs(1_104_537_600))
+        );
+        assert_eq!(reader.as_str(), ";");
+
+        for input in &[
+            "Sun, 06 Foo 1994 08:49:37 GMT",
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:60 GMT",
+            "Sun 06 Nov 1994 08:49:37 GMT",
+            "06 Nov 1994 08:49:37 GMT",
+        ] {
+            let mut reader = StringReader::new(input);
+
+            assert!(reader.read_http_date().is_err());
+            assert_eq!(reader.as_str(), *input);
+        }
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        let mut reader = StringReader::new("1700000000.123456789123 12 x");
+
+        assert_eq!(
+            reader.read_unix_timestamp(),
+            Ok(UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789))
+        );
+
+        reader.skip_whitespace();
+
+        assert_eq!(
+            reader.read_unix_timestamp(),
+            Ok(UNIX_EPOCH + Duration::from_secs(12))
+        );
+
+        reader.skip_whitespace();
+
+        assert!(reader.read_unix_timestamp().is_err());
+    }
+}
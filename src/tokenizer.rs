@@ -0,0 +1,230 @@
+use core::iter::FusedIterator;
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{ErrorKind, ParseError, Span, StringReader};
+
+/// Token produced by a tokenizer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Token<'a, K> {
+    /// Token kind.
+    pub kind: K,
+    /// Source text of the token.
+    pub text: &'a str,
+    /// Location of the token within the input.
+    pub span: Span,
+}
+
+/// Token rule.
+type Rule<'a, K> = Box<dyn FnMut(&mut StringReader<'a>) -> Option<K> + 'a>;
+
+/// Simple tokenizer.
+///
+/// The tokenizer splits the input into tokens using a list of rules. A rule
+/// is a function that tries to consume a token from a given reader and
+/// returns its kind. The rules are tried in the order they were added and the
+/// first matching rule wins. The reader is rewound automatically if a rule
+/// does not match. Rules that match without consuming any input are treated
+/// as not matching.
+///
+/// Whitespace between tokens is skipped by default.
+///
+/// # Example
+///
+/// ```
+/// use str_reader::{StringReader, Tokenizer};
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// enum Kind {
+///     Number,
+///     Plus,
+/// }
+///
+/// let tokenizer = Tokenizer::new(StringReader::new("1 + 23"))
+///     .rule(|r| r.read_int_inline::<u64>().ok().map(|_| Kind::Number))
+///     .rule(|r| r.match_char('+').ok().map(|_| Kind::Plus));
+///
+/// let tokens = tokenizer
+///     .map(|t| t.map(|t| (t.kind, t.text)))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(tokens, [(Kind::Number, "1"), (Kind::Plus, "+"), (Kind::Number, "23")]);
+/// ```
+pub struct Tokenizer<'a, K> {
+    reader: StringReader<'a>,
+    rules: Vec<Rule<'a, K>>,
+    skip_whitespace: bool,
+    failed: bool,
+}
+
+impl<'a, K> Tokenizer<'a, K> {
+    /// Create a new tokenizer reading tokens from a given reader.
+    #[inline]
+    pub fn new(reader: StringReader<'a>) -> Self {
+        Self {
+            reader,
+            rules: Vec::new(),
+            skip_whitespace: true,
+            failed: false,
+        }
+    }
+
+    /// Add a given token rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - a function that tries to consume a token and returns its
+    ///   kind on success
+    pub fn rule<F>(mut self, rule: F) -> Self
+    where
+        F: FnMut(&mut StringReader<'a>) -> Option<K> + 'a,
+    {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Enable/disable skipping of whitespace between tokens (enabled by
+    /// default).
+    #[inline]
+    pub fn skip_whitespace(mut self, enabled: bool) -> Self {
+        self.skip_whitespace = enabled;
+        self
+    }
+
+    /// Get the underlying reader.
+    #[inline]
+    pub fn reader(&self) -> &StringReader<'a> {
+        &self.reader
+    }
+
+    /// Take the underlying reader.
+    #[inline]
+    pub fn into_reader(self) -> StringReader<'a> {
+        self.reader
+    }
+
+    /// Read the next token.
+    fn next_token(&mut self) -> Option<Result<Token<'a, K>, ParseError>> {
+        if self.skip_whitespace {
+            self.reader.skip_whitespace();
+        }
+
+        if self.reader.is_empty() {
+            return None;
+        }
+
+        let start = self.reader.checkpoint();
+
+        for rule in &mut self.rules {
+            if let Some(kind) = rule(&mut self.reader) {
+                let end = self.reader.checkpoint();
+
+                let text = &start.rest[..start.rest.len() - end.rest.len()];
+
+                if !text.is_empty() {
+                    let token = Token {
+                        kind,
+                        text,
+                        span: Span {
+                            start: start.position(),
+                            end: end.position(),
+                        },
+                    };
+
+                    return Some(Ok(token));
+                }
+            }
+
+            self.reader.rewind(start);
+        }
+
+        let err = self
+            .reader
+            .error(ErrorKind::NoMatch)
+            .with_found(self.reader.current_char());
+
+        Some(Err(err))
+    }
+}
+
+impl<'a, K> Iterator for Tokenizer<'a, K> {
+    type Item = Result<Token<'a, K>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let res = self.next_token();
+
+        if let Some(Err(_)) = res {
+            self.failed = true;
+        }
+
+        res
+    }
+}
+
+impl<K> FusedIterator for Tokenizer<'_, K> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader, Tokenizer};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum Kind {
+        Ident,
+        Number,
+        Le,
+        Lt,
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        let reader = StringReader::new("abc <= 12\n< x ?");
+
+        let mut tokenizer = Tokenizer::new(reader)
+            .rule(|r| {
+                let ident = r.read_while(|c| c.is_ascii_alphabetic());
+
+                Some(Kind::Ident).filter(|_| !ident.is_empty())
+            })
+            .rule(|r| r.read_int_inline::<u32>().ok().map(|_| Kind::Number))
+            .rule(|r| r.match_str("<=").ok().map(|_| Kind::Le))
+            .rule(|r| r.match_char('<').ok().map(|_| Kind::Lt));
+
+        let token = tokenizer.next().unwrap().unwrap();
+
+        assert_eq!(token.kind, Kind::Ident);
+        assert_eq!(token.text, "abc");
+        assert_eq!(token.span.range(), 0..3);
+
+        let token = tokenizer.next().unwrap().unwrap();
+
+        assert_eq!(token.kind, Kind::Le);
+        assert_eq!(token.span.range(), 4..6);
+
+        let token = tokenizer.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Number, "12"));
+
+        let token = tokenizer.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Lt, "<"));
+        assert_eq!(token.span.start.line, 2);
+
+        let token = tokenizer.next().unwrap().unwrap();
+
+        assert_eq!((token.kind, token.text), (Kind::Ident, "x"));
+
+        let err = tokenizer.next().unwrap().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.found(), Some('?'));
+        assert_eq!(err.position().offset, 14);
+
+        assert!(tokenizer.next().is_none());
+        assert_eq!(tokenizer.into_reader().as_str(), "?");
+    }
+}
@@ -0,0 +1,336 @@
+use core::{convert::TryFrom, time::Duration};
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Duration units and their lengths in nanoseconds. A unit must come before
+/// all units that are its prefixes (e.g. `ms` before `m`).
+const DURATION_UNITS: &[(&str, u128)] = &[
+    ("ns", 1),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("d", 86_400_000_000_000),
+];
+
+/// Byte size prefixes (in lowercase).
+const SIZE_PREFIXES: &[u8] = b"kmgtpe";
+
+/// Maximum number of fractional digits taken into account.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+impl<'a> StringReader<'a> {
+    /// Read a duration starting at the current position.
+    ///
+    /// The duration consists of one or more components, each of them being a
+    /// decimal number with an optional fractional part followed by a unit
+    /// (`ns`, `us` or `µs`, `ms`, `s`, `m`, `h` or `d`). The components are
+    /// concatenated without any whitespace (e.g. `1h30m15s` or `1.5d`) and
+    /// their values are added together. The input won't be advanced if the
+    /// duration cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("1m30.5s timeout");
+    ///
+    /// assert_eq!(reader.read_duration(), Ok(Duration::from_millis(90_500)));
+    /// assert_eq!(reader.as_str(), " timeout");
+    /// ```
+    pub fn read_duration(&mut self) -> Result<Duration, ParseError> {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let mut nanos = 0u128;
+
+            loop {
+                let (value, fraction) = reader.read_decimal("duration")?;
+
+                let unit = DURATION_UNITS
+                    .iter()
+                    .find(|(name, _)| reader.starts_with(name));
+
+                let (name, unit) = match unit {
+                    Some(unit) => unit,
+                    None => {
+                        let kind = if reader.is_empty() {
+                            ErrorKind::EmptyInput
+                        } else {
+                            ErrorKind::NoMatch
+                        };
+
+                        let err = reader
+                            .error(kind)
+                            .with_expected(Expected::Description("duration unit"))
+                            .with_found(reader.current_char());
+
+                        return Err(err);
+                    }
+                };
+
+                reader.split_to(name.len());
+
+                nanos = value
+                    .checked_mul(*unit)
+                    .and_then(|n| n.checked_add(fraction.mul(*unit)))
+                    .and_then(|n| n.checked_add(nanos))
+                    .ok_or_else(|| {
+                        ParseError::new(ErrorKind::InvalidValue, start)
+                            .with_expected(Expected::Description("duration"))
+                    })?;
+
+                if !reader.current_char().is_some_and(|c| c.is_ascii_digit()) {
+                    break;
+                }
+            }
+
+            let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| {
+                ParseError::new(ErrorKind::InvalidValue, start)
+                    .with_expected(Expected::Description("duration"))
+            })?;
+
+            Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+        })
+    }
+
+    /// Read a human-readable byte size starting at the current position and
+    /// return the number of bytes.
+    ///
+    /// The size is a decimal number with an optional fractional part followed
+    /// by an optional unit. The unit consists of an optional SI prefix (`k`,
+    /// `M`, `G`, `T`, `P` or `E`, multiples of 1000) or IEC prefix (`Ki`,
+    /// `Mi`, `Gi`, `Ti`, `Pi` or `Ei`, multiples of 1024) and an optional `B`
+    /// (e.g. `512`, `1.5k`, `10MiB` or `2GB`). The unit is case-insensitive
+    /// and it must immediately follow the number. Fractional bytes are
+    /// truncated. The input won't be advanced if the size cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("10MiB 1.5kb");
+    ///
+    /// assert_eq!(reader.read_byte_size(), Ok(10 * 1024 * 1024));
+    ///
+    /// reader.skip_whitespace();
+    ///
+    /// assert_eq!(reader.read_byte_size(), Ok(1500));
+    /// ```
+    pub fn read_byte_size(&mut self) -> Result<u64, ParseError> {
+        self.transaction(|reader| {
+            let start = reader.position;
+
+            let (value, fraction) = reader.read_decimal("byte size")?;
+
+            let bytes = reader.as_str().as_bytes();
+
+            let prefix = bytes
+                .first()
+                .and_then(|b| SIZE_PREFIXES.iter().position(|p| b.eq_ignore_ascii_case(p)));
+
+            let mut multiplier = 1u128;
+            let mut len = 0;
+
+            if let Some(exp) = prefix {
+                len = 1;
+
+                let base = if bytes.get(1).map(u8::to_ascii_lowercase) == Some(b'i') {
+                    len = 2;
+                    1024
+                } else {
+                    1000
+                };
+
+                multiplier = (0..=exp).fold(1, |res, _| res * base);
+            }
+
+            if bytes.get(len).map(u8::to_ascii_lowercase) == Some(b'b') {
+                len += 1;
+            }
+
+            reader.split_to(len);
+
+            value
+                .checked_mul(multiplier)
+                .and_then(|n| n.checked_add(fraction.mul(multiplier)))
+                .and_then(|n| u64::try_from(n).ok())
+                .ok_or_else(|| {
+                    ParseError::new(ErrorKind::InvalidValue, start)
+                        .with_expected(Expected::Description("byte size"))
+                })
+        })
+    }
+
+    /// Read a decimal number with an optional fractional part and return its
+    /// integer part and its fractional part.
+    pub(crate) fn read_decimal(
+        &mut self,
+        expected: &'static str,
+    ) -> Result<(u128, Fraction), ParseError> {
+        let digits = self.read_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            let kind = if self.is_empty() {
+                ErrorKind::EmptyInput
+            } else {
+                ErrorKind::NoMatch
+            };
+
+            let err = self
+                .error(kind)
+                .with_expected(Expected::Description(expected))
+                .with_found(self.current_char());
+
+            return Err(err);
+        }
+
+        let value = digits.bytes().try_fold(0u128, |res, b| {
+            res.checked_mul(10)?.checked_add(u128::from(b - b'0'))
+        });
+
+        let value = value.ok_or_else(|| {
+            self.error(ErrorKind::InvalidValue)
+                .with_expected(Expected::Description(expected))
+        })?;
+
+        let mut fraction = Fraction::default();
+
+        if self.starts_with(".") && self.as_str()[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            self.skip_char();
+
+            let digits = self.read_while(|c| c.is_ascii_digit());
+
+            for b in digits.bytes().take(MAX_FRACTION_DIGITS) {
+                fraction.digits = fraction.digits * 10 + u128::from(b - b'0');
+                fraction.scale *= 10;
+            }
+        }
+
+        Ok((value, fraction))
+    }
+}
+
+/// Fractional part of a decimal number.
+pub(crate) struct Fraction {
+    digits: u128,
+    scale: u128,
+}
+
+impl Fraction {
+    /// Multiply the fraction by a given integer and truncate the result.
+    pub(crate) fn mul(&self, n: u128) -> u128 {
+        self.digits * n / self.scale
+    }
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Self {
+            digits: 0,
+            scale: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_duration() {
+        let mut reader = StringReader::new("1h30m15s 250ms 1.5d 2us;3µs 0.000000001s 10");
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_secs(5415)));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_millis(250)));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_secs(129_600)));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_micros(2)));
+
+        reader.skip_char();
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_micros(3)));
+
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_duration(), Ok(Duration::from_nanos(1)));
+
+        reader.skip_whitespace();
+
+        let err = reader.read_duration().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::EmptyInput);
+        assert_eq!(reader.as_str(), "10");
+
+        let mut reader = StringReader::new("1h30x");
+
+        let err = reader.read_duration().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::NoMatch);
+        assert_eq!(err.position().column, 5);
+        assert_eq!(reader.as_str(), "1h30x");
+
+        let mut reader = StringReader::new("99999999999999999999999d");
+
+        let err = reader.read_duration().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(reader.read_duration().unwrap_err().position().column, 1);
+        assert!(StringReader::new("h").read_duration().is_err());
+    }
+
+    #[test]
+    fn test_byte_size() {
+        let mut reader = StringReader::new("512 1k 2.5KiB 3MB 1gi 1e 16EiB .5k 1kx");
+
+        let expected = [
+            512,
+            1000,
+            2560,
+            3_000_000,
+            1 << 30,
+            1_000_000_000_000_000_000,
+        ];
+
+        for size in &expected {
+            assert_eq!(reader.read_byte_size(), Ok(*size));
+
+            reader.skip_whitespace();
+        }
+
+        let err = reader.read_byte_size().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(reader.as_str(), "16EiB .5k 1kx");
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(
+            reader.read_byte_size().unwrap_err().kind(),
+            ErrorKind::NoMatch
+        );
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert_eq!(reader.read_byte_size(), Ok(1000));
+        assert_eq!(reader.as_str(), "x");
+    }
+}
@@ -0,0 +1,120 @@
+use ::uuid::Uuid;
+
+use crate::{ErrorKind, Expected, ParseError, StringReader};
+
+/// Length of a hyphenated UUID.
+const HYPHENATED_LEN: usize = 36;
+
+/// Length of a simple UUID.
+const SIMPLE_LEN: usize = 32;
+
+impl<'a> StringReader<'a> {
+    /// Read a UUID starting at the current position. Both the hyphenated
+    /// form (e.g. `550e8400-e29b-41d4-a716-446655440000`) and the simple form
+    /// (32 hexadecimal digits) are accepted. The UUID cannot be followed by
+    /// another hexadecimal digit. The input won't be advanced in case of an
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use str_reader::StringReader;
+    ///
+    /// let mut reader = StringReader::new("550e8400-e29b-41d4-a716-446655440000,status=200");
+    ///
+    /// let id = reader.read_uuid().unwrap();
+    ///
+    /// assert_eq!(id.to_string(), "550e8400-e29b-41d4-a716-446655440000");
+    /// assert_eq!(reader.as_str(), ",status=200");
+    /// ```
+    pub fn read_uuid(&mut self) -> Result<Uuid, ParseError> {
+        let bytes = self.as_str().as_bytes();
+
+        let is_hyphenated = bytes.len() >= HYPHENATED_LEN
+            && bytes[..HYPHENATED_LEN]
+                .iter()
+                .enumerate()
+                .all(|(index, b)| match index {
+                    8 | 13 | 18 | 23 => *b == b'-',
+                    _ => b.is_ascii_hexdigit(),
+                });
+
+        let len = if is_hyphenated {
+            HYPHENATED_LEN
+        } else {
+            bytes.iter().take_while(|b| b.is_ascii_hexdigit()).count()
+        };
+
+        let is_terminated = !bytes.get(len).is_some_and(u8::is_ascii_hexdigit);
+
+        let uuid = if is_terminated && (len == HYPHENATED_LEN || len == SIMPLE_LEN) {
+            Uuid::try_parse(&self.as_str()[..len]).ok()
+        } else {
+            None
+        };
+
+        match uuid {
+            Some(uuid) => {
+                self.split_to(len);
+
+                Ok(uuid)
+            }
+            None => {
+                let kind = if bytes.is_empty() {
+                    ErrorKind::EmptyInput
+                } else if len > 0 {
+                    ErrorKind::InvalidValue
+                } else {
+                    ErrorKind::NoMatch
+                };
+
+                let err = self
+                    .error(kind)
+                    .with_expected(Expected::Description("UUID"))
+                    .with_found(self.current_char());
+
+                Err(err)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ErrorKind, StringReader};
+
+    #[test]
+    fn test_uuid() {
+        let input = "id=550E8400-e29b-41d4-a716-446655440000,550e8400e29b41d4a716446655440000 \
+                     550e8400-e29b-41d4-a716-4466554400001 550e8400";
+
+        let mut reader = StringReader::new(input);
+
+        reader.match_str("id=").unwrap();
+
+        let a = reader.read_uuid().unwrap();
+
+        reader.match_char(',').unwrap();
+
+        let b = reader.read_uuid().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a.as_u128(), 0x550e8400e29b41d4a716446655440000);
+
+        reader.skip_whitespace();
+
+        let err = reader.read_uuid().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+
+        reader.read_word();
+        reader.skip_whitespace();
+
+        assert!(reader.read_uuid().is_err());
+        assert_eq!(reader.as_str(), "550e8400");
+        assert_eq!(
+            StringReader::new("-").read_uuid().unwrap_err().kind(),
+            ErrorKind::NoMatch
+        );
+    }
+}